@@ -0,0 +1,59 @@
+// Benchmarks parse, part 1 and part 2 of every registered day, one at a time.
+//
+// Each day runs against its cached input in `inputs/<year>/dayNN.txt`, or its
+// example fixture when there is none, and days with neither are skipped.
+// Save a baseline before optimising and compare against it afterwards:
+//
+//     cargo bench -- --save-baseline before
+//     cargo bench -- --baseline before
+//
+// A filter narrows the run down, e.g. `cargo bench -- 2022/day16`.
+
+use std::{fs, path::PathBuf};
+
+use aoc::aoc_days::{self, Registration};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+fn input(r: &Registration) -> Option<(&'static str, String)> {
+    let cached = PathBuf::from("inputs")
+        .join(r.year.to_string())
+        .join(format!("day{:02}.txt", r.day));
+    let example = PathBuf::from("examples")
+        .join(r.year.to_string())
+        .join(format!("day{:02}", r.day))
+        .join("example.txt");
+
+    [("input", cached), ("example", example)]
+        .into_iter()
+        .find_map(|(kind, path)| Some((kind, fs::read_to_string(path).ok()?)))
+}
+
+fn days(c: &mut Criterion) {
+    for r in aoc_days::registered() {
+        let Some((kind, input)) = input(r) else {
+            continue;
+        };
+        let parsed = match (r.parse)(input.clone()) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!("skipping {}/day{:02}:\n{}", r.year, r.day, err);
+                continue;
+            }
+        };
+
+        let mut group = c.benchmark_group(format!("{}/day{:02}/{}", r.year, r.day, kind));
+        // the slow days would otherwise take minutes each
+        group.sample_size(10);
+
+        group.bench_function("parse", |b| {
+            b.iter_batched(|| input.clone(), r.parse, BatchSize::SmallInput)
+        });
+        group.bench_function("part1", |b| b.iter(|| (r.part1)(parsed.as_ref())));
+        group.bench_function("part2", |b| b.iter(|| (r.part2)(parsed.as_ref())));
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
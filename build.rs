@@ -0,0 +1,86 @@
+// Generates one test per example fixture listed in `examples/<year>/dayNN/answers.txt`.
+
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    println!("cargo:rerun-if-changed={}", root.display());
+
+    let mut tests = String::new();
+
+    for year_dir in subdirs(&root) {
+        let Some(year) = year_dir
+            .file_name()
+            .and_then(|name| name.to_str()?.parse::<i32>().ok())
+        else {
+            continue;
+        };
+        println!("cargo:rerun-if-changed={}", year_dir.display());
+
+        for dir in subdirs(&year_dir) {
+            let Some(day) = dir
+                .file_name()
+                .and_then(|name| name.to_str()?.strip_prefix("day")?.parse::<i32>().ok())
+            else {
+                continue;
+            };
+
+            fixtures(&mut tests, year, day, &dir);
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("fixtures.rs");
+    fs::write(out, tests).unwrap();
+}
+
+fn subdirs(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<_> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn fixtures(tests: &mut String, year: i32, day: i32, dir: &Path) {
+    let answers = dir.join("answers.txt");
+    println!("cargo:rerun-if-changed={}", answers.display());
+    let Ok(answers) = fs::read_to_string(&answers) else {
+        return;
+    };
+
+    for line in answers.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        // a bad line stops the tests compiling, with the build of everything else unaffected
+        let [file, part1, part2] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+            let message = format!(
+                "{}: expected `<input> <part1> <part2>`, got `{line}`",
+                dir.join("answers.txt").display()
+            );
+            writeln!(tests, "compile_error!({message:?});\n").unwrap();
+            continue;
+        };
+
+        let name = file
+            .trim_end_matches(".txt")
+            .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+
+        writeln!(
+            tests,
+            "#[test]\nfn y{year}_day{day:02}_{name}() {{\n    check_fixture({year}, {day}, {:?}, {part1:?}, {part2:?});\n}}\n",
+            dir.join(file).display().to_string(),
+        )
+        .unwrap();
+    }
+}
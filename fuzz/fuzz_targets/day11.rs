@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc::{solution::Solution, y2022::day11::Day11};
+use libfuzzer_sys::fuzz_target;
+
+// whatever it's given, parsing should come back with an input or an error
+fuzz_target!(|input: &str| {
+    let _ = Day11::parse(input.to_owned());
+});
@@ -0,0 +1,392 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    client::{Hint, Verdict},
+    runner::{DayReport, Status},
+    solution::{Answer, Part},
+};
+
+/// Answers known to be correct for our real inputs, one `<day> <part> <answer>` per line.
+/// Answers the site turned down are kept as `wrong <day> <part> <answer>`, or with
+/// `too-high` or `too-low` in place of `wrong` when the site said which way it was off.
+pub struct AnswerStore {
+    path: PathBuf,
+    known: BTreeMap<(i32, u8), String>,
+    wrong: BTreeMap<(i32, u8), Vec<Wrong>>,
+}
+
+/// An answer the site has already turned down.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wrong {
+    pub answer: String,
+    pub hint: Option<Hint>,
+}
+
+impl Wrong {
+    // the word in front of the line, never mistaken for the day that starts a right answer
+    fn marker(&self) -> &'static str {
+        match self.hint {
+            None => "wrong",
+            Some(Hint::TooHigh) => "too-high",
+            Some(Hint::TooLow) => "too-low",
+        }
+    }
+
+    fn hint(marker: &str) -> Option<Option<Hint>> {
+        match marker {
+            "wrong" => Some(None),
+            "too-high" => Some(Some(Hint::TooHigh)),
+            "too-low" => Some(Some(Hint::TooLow)),
+            _ => None,
+        }
+    }
+
+    // whether `answer` is on the wrong side of this one as well
+    fn rules_out(&self, answer: &str) -> bool {
+        let bounds = answer
+            .parse::<i64>()
+            .ok()
+            .zip(self.answer.parse::<i64>().ok());
+        match (self.hint, bounds) {
+            (Some(Hint::TooHigh), Some((answer, high))) => answer >= high,
+            (Some(Hint::TooLow), Some((answer, low))) => answer <= low,
+            _ => answer == self.answer,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Mismatch {
+    pub day: i32,
+    pub part: u8,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for Mismatch {
+    // diff style, so multi-line pictures stay readable
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "day {} part {}:", self.day, self.part)?;
+        for line in unescape(&self.expected).lines() {
+            writeln!(f, "- {line}")?;
+        }
+        for line in unescape(&self.actual).lines() {
+            writeln!(f, "+ {line}")?;
+        }
+        Ok(())
+    }
+}
+
+impl AnswerStore {
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+
+        let mut known = BTreeMap::new();
+        let mut wrong: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for (n, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (hint, line) = match line.split_once(' ') {
+                Some((marker, rest)) => match Wrong::hint(marker) {
+                    Some(hint) => (Some(hint), rest),
+                    None => (None, line),
+                },
+                None => (None, line),
+            };
+            let mut fields = line.splitn(3, ' ');
+            match (
+                fields.next().and_then(|d| d.parse().ok()),
+                fields.next().and_then(|p| p.parse().ok()),
+                fields.next(),
+            ) {
+                (Some(day), Some(part), Some(answer)) => match hint {
+                    Some(hint) => wrong.entry((day, part)).or_default().push(Wrong {
+                        answer: answer.to_owned(),
+                        hint,
+                    }),
+                    None => {
+                        known.insert((day, part), answer.to_owned());
+                    }
+                },
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "{}:{}: expected `<day> <part> <answer>`",
+                            path.display(),
+                            n + 1
+                        ),
+                    ))
+                }
+            }
+        }
+
+        Ok(AnswerStore { path, known, wrong })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut lines: Vec<_> = self
+            .known
+            .iter()
+            .map(|(&(day, part), answer)| ((day, part), format!("{day} {part} {answer}\n")))
+            .chain(self.wrong.iter().flat_map(|(&(day, part), wrong)| {
+                wrong.iter().map(move |wrong| {
+                    let line = format!("{} {day} {part} {}\n", wrong.marker(), wrong.answer);
+                    ((day, part), line)
+                })
+            }))
+            .collect();
+        // stable, so each part's wrong answers stay in the order they were given
+        lines.sort_by_key(|&(key, _)| key);
+
+        let contents: String = lines.into_iter().map(|(_, line)| line).collect();
+
+        fs::write(&self.path, contents)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, day: i32, part: u8) -> Option<&str> {
+        self.known.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Why submitting `answer` would be pointless, if the store already knows it.
+    pub fn known_wrong(&self, day: i32, part: u8, answer: &str) -> Option<String> {
+        if let Some(right) = self.get(day, part) {
+            return Some(format!("this part is already solved, with {right}"));
+        }
+
+        self.wrong
+            .get(&(day, part))?
+            .iter()
+            .find(|wrong| wrong.rules_out(answer))
+            .map(|wrong| match wrong.hint {
+                None => format!("{} was already turned down", wrong.answer),
+                Some(Hint::TooHigh) => format!("{} was already too high", wrong.answer),
+                Some(Hint::TooLow) => format!("{} was already too low", wrong.answer),
+            })
+    }
+
+    /// Keeps what the site said about `answer`.
+    pub fn remember(&mut self, day: i32, part: u8, answer: &str, verdict: Verdict) {
+        match verdict {
+            Verdict::Right => {
+                self.known.insert((day, part), answer.to_owned());
+            }
+            Verdict::Wrong(hint) => self.wrong.entry((day, part)).or_default().push(Wrong {
+                answer: answer.to_owned(),
+                hint,
+            }),
+            Verdict::Wait { .. } | Verdict::AlreadySolved => {}
+        }
+    }
+
+    /// Remembers the answers of every day that ran successfully.
+    pub fn record(&mut self, reports: &[DayReport]) {
+        for r in reports.iter().filter(|r| r.status == Status::Ok) {
+            for (part, answer) in [(1, &r.part1), (2, &r.part2)] {
+                if let Some(answer) = stored(answer) {
+                    self.known.insert((r.day, part), answer);
+                }
+            }
+        }
+    }
+
+    /// Every recorded answer that this run disagrees with, ignoring parts that were not run.
+    pub fn check(&self, reports: &[DayReport], only: Option<Part>) -> Vec<Mismatch> {
+        let mut mismatches = Vec::new();
+
+        for r in reports {
+            for (part, answer) in [(1, &r.part1), (2, &r.part2)] {
+                if only.is_some_and(|only| only.number() != part) {
+                    continue;
+                }
+                let Some(expected) = self.get(r.day, part) else {
+                    continue;
+                };
+                let actual = stored(answer).unwrap_or_else(|| format!("({})", r.status));
+                if actual != expected {
+                    mismatches.push(Mismatch {
+                        day: r.day,
+                        part,
+                        expected: expected.to_owned(),
+                        actual,
+                    });
+                }
+            }
+        }
+
+        mismatches
+    }
+}
+
+// one line per answer, so pictures get their newlines escaped
+fn stored(answer: &Answer) -> Option<String> {
+    match answer {
+        Answer::Unsolved => None,
+        answer => Some(
+            answer
+                .to_string()
+                .trim_matches('\n')
+                .replace('\\', "\\\\")
+                .replace('\n', "\\n"),
+        ),
+    }
+}
+
+fn unescape(answer: &str) -> String {
+    let mut out = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                out.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                out.push('\\');
+                chars.next();
+            }
+            (c, _) => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf, process};
+
+    use super::{stored, unescape, AnswerStore, Wrong};
+    use crate::{
+        client::{Hint, Verdict},
+        runner::{DayReport, Status},
+        solution::{Answer, Part},
+    };
+
+    fn store_at(name: &str) -> (PathBuf, AnswerStore) {
+        let path = env::temp_dir().join(format!("aoc-answers-{name}-{}.txt", process::id()));
+        let _ = fs::remove_file(&path);
+        let store = AnswerStore::load(&path).unwrap();
+        (path, store)
+    }
+
+    fn report(day: i32, part1: Answer, part2: Answer) -> DayReport {
+        DayReport {
+            year: 2022,
+            day,
+            part1,
+            part2,
+            status: Status::Ok,
+            timings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn pictures_are_kept_on_one_line() {
+        let picture = Answer::Picture("#.\\\n.#\n".to_owned());
+        let line = stored(&picture).unwrap();
+
+        assert_eq!(line, "#.\\\\\\n.#");
+        assert_eq!(unescape(&line), "#.\\\n.#");
+        assert_eq!(stored(&Answer::Unsolved), None);
+    }
+
+    #[test]
+    fn saved_answers_load_back_the_same() {
+        let (path, mut store) = store_at("round-trip");
+        store.record(&[
+            report(1, 24000.into(), 45000.into()),
+            report(10, 13140.into(), Answer::Picture("##..\n..##\n".to_owned())),
+        ]);
+        // right answers that look like the markers in front of wrong ones
+        store.remember(5, 1, "wrong 1 2", Verdict::Right);
+        store.remember(5, 2, "!= CMZ", Verdict::Right);
+        store.remember(2, 1, "15", Verdict::Wrong(None));
+        store.remember(2, 1, "20", Verdict::Wrong(Some(Hint::TooHigh)));
+        store.remember(2, 2, "3", Verdict::Wrong(Some(Hint::TooLow)));
+        store.save().unwrap();
+
+        let loaded = AnswerStore::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.known, store.known);
+        assert_eq!(loaded.wrong, store.wrong);
+        assert_eq!(loaded.get(5, 1), Some("wrong 1 2"));
+        assert_eq!(loaded.get(5, 2), Some("!= CMZ"));
+        assert_eq!(
+            loaded.wrong[&(2, 1)],
+            [
+                Wrong {
+                    answer: "15".to_owned(),
+                    hint: None
+                },
+                Wrong {
+                    answer: "20".to_owned(),
+                    hint: Some(Hint::TooHigh)
+                },
+            ]
+        );
+        // the picture comes back as it was recorded
+        let rerun = report(10, 13140.into(), Answer::Picture("##..\n..##".to_owned()));
+        assert!(loaded.check(&[rerun], None).is_empty());
+    }
+
+    #[test]
+    fn wrong_answers_rule_out_what_they_can() {
+        let (_, mut store) = store_at("wrong");
+        store.remember(2, 1, "20", Verdict::Wrong(Some(Hint::TooHigh)));
+        store.remember(2, 2, "CMZ", Verdict::Wrong(None));
+
+        assert!(store.known_wrong(2, 1, "25").is_some());
+        assert!(store.known_wrong(2, 1, "19").is_none());
+        assert!(store.known_wrong(2, 2, "CMZ").is_some());
+        assert!(store.known_wrong(2, 2, "MCD").is_none());
+    }
+
+    #[test]
+    fn check_only_looks_at_the_parts_that_ran() {
+        let (_, mut store) = store_at("check");
+        store.record(&[report(1, 24000.into(), 45000.into())]);
+        let run = [report(1, 24000.into(), 1.into())];
+
+        let mismatches = store.check(&run, None);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!((mismatches[0].day, mismatches[0].part), (1, 2));
+        assert_eq!(mismatches[0].expected, "45000");
+        assert_eq!(mismatches[0].actual, "1");
+
+        assert!(store.check(&run, Some(Part::One)).is_empty());
+        assert_eq!(store.check(&run, Some(Part::Two)).len(), 1);
+    }
+
+    #[test]
+    fn unknown_lines_are_an_error() {
+        let (path, _) = store_at("malformed");
+        fs::write(&path, "# day part answer\n1 1 24000\nnot an answer\n").unwrap();
+
+        let err = AnswerStore::load(&path).err().unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(err
+            .to_string()
+            .ends_with(":3: expected `<day> <part> <answer>`"));
+    }
+}
@@ -1,51 +1,100 @@
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-// mod day18;
-// mod day19;
-// mod day20;
-mod day21;
-// mod day22;
-mod day23;
-mod day24;
-use get_inputs::run_on_input;
-
-pub fn run_day(day: i32) -> Result<(), get_inputs::Error> {
-    match day {
-        1 => run_on_input(day, day1::run, day1::parse),
-        2 => run_on_input(day, day2::run, day2::parse),
-        3 => run_on_input(day, day3::run, day3::parse),
-        4 => run_on_input(day, day4::run, day4::parse),
-        5 => run_on_input(day, day5::run, day5::parse),
-        6 => run_on_input(day, day6::run, day6::parse),
-        7 => run_on_input(day, day7::run, day7::parse),
-        8 => run_on_input(day, day8::run, day8::parse),
-        9 => run_on_input(day, day9::run, day9::parse),
-        10 => run_on_input(day, day10::run, day10::parse),
-        11 => run_on_input(day, day11::run, day11::parse),
-        12 => run_on_input(day, day12::run, day12::parse),
-        13 => run_on_input(day, day13::run, day13::parse),
-        14 => run_on_input(day, day14::run, day14::parse),
-        15 => run_on_input(day, day15::run, day15::parse),
-        16 => run_on_input(day, day16::run, day16::parse),
-        17 => run_on_input(day, day17::run, day17::parse),
-        21 => run_on_input(day, day21::run, day21::parse),
-        23 => run_on_input(day, day23::run, day23::parse),
-        24 => run_on_input(day, day24::run, day24::parse),
-        _ => Ok(()),
+#[cfg(test)]
+// only compiled to keep it valid, nothing uses it
+#[allow(dead_code)]
+mod template;
+pub mod y2022;
+
+use std::any::Any;
+
+use rand::RngCore;
+
+use crate::{
+    parse_error::ParseError,
+    solution::{parse, solve, Answer, Comparison, Part, Solution, Solved},
+};
+
+pub type Solver = fn(String, Option<Part>) -> Result<Solved, ParseError>;
+
+/// A solution that has added itself to the registry with `register!`.
+pub struct Registration {
+    pub year: i32,
+    pub day: i32,
+    pub parts: &'static [Part],
+    pub solver: Solver,
+    // each phase on its own, with the parsed input hidden behind `Any`,
+    // so benchmarks can time them separately
+    pub parse: fn(String) -> Result<Box<dyn Any>, ParseError>,
+    pub part1: fn(&dyn Any) -> Answer,
+    pub part2: fn(&dyn Any) -> Answer,
+    pub generate: fn(&mut dyn RngCore, usize) -> Option<String>,
+    pub reference: fn(&dyn Any) -> Option<Vec<Comparison>>,
+}
+
+impl Registration {
+    pub const fn of<S: Solution>() -> Self
+    where
+        S::Input: 'static,
+    {
+        Registration {
+            year: S::YEAR,
+            day: S::DAY,
+            parts: S::PARTS,
+            solver: solve::<S>,
+            parse: |input| Ok(Box::new(parse::<S>(input)?)),
+            part1: |input| S::part1(downcast::<S>(input)),
+            part2: |input| S::part2(downcast::<S>(input)),
+            generate: S::generate,
+            reference: |input| S::reference(downcast::<S>(input)),
+        }
     }
 }
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .expect("input was parsed by a different solution")
+}
+
+inventory::collect!(Registration);
+
+/// Registers a [`Solution`] under its year and day, next to where it is defined.
+macro_rules! register {
+    ($solution:ty) => {
+        inventory::submit! {
+            $crate::aoc_days::Registration::of::<$solution>()
+        }
+    };
+}
+pub(crate) use register;
+
+/// Every registered solution, ordered by year and day.
+pub fn registered() -> Vec<&'static Registration> {
+    let mut all: Vec<_> = inventory::iter::<Registration>.into_iter().collect();
+    all.sort_by_key(|r| (r.year, r.day));
+    all
+}
+
+/// Every year with at least one solution, oldest first.
+pub fn years() -> Vec<i32> {
+    let mut years: Vec<_> = registered().iter().map(|r| r.year).collect();
+    years.dedup();
+    years
+}
+
+/// The year used when none is asked for.
+pub fn latest() -> i32 {
+    years().last().copied().unwrap_or_default()
+}
+
+pub fn find(year: i32, day: i32) -> Option<&'static Registration> {
+    inventory::iter::<Registration>
+        .into_iter()
+        .find(|r| (r.year, r.day) == (year, day))
+}
+
+pub fn solver(year: i32, day: i32) -> Option<Solver> {
+    find(year, day).map(|r| r.solver)
+}
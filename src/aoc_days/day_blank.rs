@@ -1,11 +0,0 @@
-// This file does not get checked by rust as it does not participate in the module tree
-
-type Input = String;
-
-pub fn parse(input: String) -> Result<Input, get_inputs::Error> {
-    Ok("unimplemented".to_owned())
-}
-
-pub fn run(input: Input) -> () {
-    println!("{:?}", input);
-}
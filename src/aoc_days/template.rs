@@ -0,0 +1,32 @@
+// `aoc new` starts every day from this file, so it is compiled with the tests to keep it valid.
+// It adds the `register!` line too, which the template leaves out so as not to be a day.
+
+use crate::{
+    parse_error::ParseError,
+    solution::{Answer, Part, Solution},
+};
+
+type Input = String;
+
+pub struct DayN;
+
+impl Solution for DayN {
+    const YEAR: i32 = 0;
+    const DAY: i32 = 0;
+    // add each part once it is solved, for `aoc list`
+    const PARTS: &'static [Part] = &[];
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(_input: &Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(_input: &Input) -> Answer {
+        Answer::Unsolved
+    }
+}
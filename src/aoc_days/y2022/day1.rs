@@ -0,0 +1,63 @@
+use rand::{Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Solution},
+};
+
+pub type Input = Vec<Vec<i32>>;
+
+pub struct Day1;
+
+register!(Day1);
+
+impl Solution for Day1 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 1;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        input
+            .split("\n\n")
+            .map(|elf| {
+                elf.lines()
+                    .map(|food| {
+                        food.parse::<i32>()
+                            .map_err(|_| ParseError::at(&input, food, "a calorie count"))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(input: &Input) -> Answer {
+        totals(input)[0].into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        totals(input)[0..3].iter().sum::<i32>().into()
+    }
+
+    // `size` elves carrying up to 15 snacks each
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let mut elves = Vec::with_capacity(size);
+        // part 2 wants the top three
+        for _ in 0..size.max(3) {
+            let snacks: Vec<String> = (0..rng.gen_range(1..=15))
+                .map(|_| rng.gen_range(1000..=70000).to_string())
+                .collect();
+            elves.push(snacks.join("\n"));
+        }
+        Some(elves.join("\n\n") + "\n")
+    }
+}
+
+fn totals(input: &Input) -> Vec<i32> {
+    let mut nums: Vec<i32> = input.iter().map(|elf| elf.iter().sum()).collect();
+
+    nums.sort();
+    nums.reverse();
+    nums
+}
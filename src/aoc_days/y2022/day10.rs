@@ -0,0 +1,99 @@
+use inpt::{inpt, Inpt};
+use rand::{Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Solution},
+    visualise::{self, Frame},
+};
+
+pub type Input = Vec<Command>;
+
+#[derive(Debug, Inpt, Clone, Copy)]
+pub enum Command {
+    #[inpt(regex = r"noop")]
+    NOOP,
+    #[inpt(regex = r"addx (-?\d+)")]
+    ADDX { v: i64 },
+}
+
+pub struct Day10;
+
+register!(Day10);
+
+impl Solution for Day10 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 10;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        input
+            .lines()
+            .map(|s| inpt(s).map_err(|_| ParseError::at(&input, s, "`noop` or `addx <number>`")))
+            .collect()
+    }
+
+    fn part1(input: &Input) -> Answer {
+        cycles(input)
+            .filter(|(i, _)| i % 40 == 19)
+            .map(|(i, reg_x)| (i as i64 + 1) * reg_x)
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        let mut crt: String = String::default();
+        let mut screen = Frame::new("", 40, 6);
+
+        for (i, reg_x) in cycles(input) {
+            let lit = reg_x.abs_diff(i as i64 % 40) <= 1;
+            crt += if lit { "#" } else { " " };
+            if i % 40 == 39 {
+                crt += "\n"
+            }
+
+            screen.set(i % 40, i / 40, if lit { '#' } else { '.' });
+            visualise::emit(|| {
+                let mut frame = screen.clone();
+                frame.caption = format!("cycle {}, X = {}", i + 1, reg_x);
+                frame
+            });
+        }
+
+        Answer::Picture(crt)
+    }
+
+    // `size` instructions, keeping the sprite on the screen
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let mut x: i64 = 1;
+        let instructions = (0..size).map(|_| {
+            if rng.gen_bool(0.3) {
+                return "noop\n".to_owned();
+            }
+            let v = rng.gen_range(-1 - x..=40 - x);
+            x += v;
+            format!("addx {}\n", v)
+        });
+        Some(instructions.collect())
+    }
+}
+
+// (cycle, value of X during that cycle)
+fn cycles(input: &Input) -> impl Iterator<Item = (usize, i64)> + '_ {
+    input
+        .iter()
+        .flat_map(|&x| match x {
+            Command::NOOP => vec![Command::NOOP],
+            Command::ADDX { v } => vec![Command::NOOP, Command::ADDX { v }],
+        })
+        .enumerate()
+        .scan(1, |reg_x, (i, cmd)| {
+            let during = *reg_x;
+            if let Command::ADDX { v } = cmd {
+                *reg_x += v
+            }
+            Some((i, during))
+        })
+}
@@ -0,0 +1,291 @@
+use std::collections::VecDeque;
+
+use inpt::{inpt, Inpt};
+use itertools::Itertools;
+use num_bigint::BigUint;
+use rand::{seq::SliceRandom, Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Comparison, Solution},
+};
+
+#[derive(Debug, Inpt, Clone)]
+pub enum Operation {
+    #[inpt(regex = r"new = old \+ (\d+)")]
+    Add(usize),
+    #[inpt(regex = r"new = old \* (\d+)")]
+    Mul(usize),
+    #[inpt(regex = r"new = old \* old")]
+    Square,
+}
+
+impl Operation {
+    fn apply(&self, x: usize) -> usize {
+        match self {
+            Operation::Add(y) => x + y,
+            Operation::Mul(y) => x * y,
+            Operation::Square => x * x,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: VecDeque<usize>,
+    operation: Operation,
+    test: usize,
+    // true, false
+    target: (usize, usize),
+}
+
+pub type Input = Vec<Monkey>;
+
+pub struct Day11;
+
+register!(Day11);
+
+impl Solution for Day11 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 11;
+
+    type Input = Input;
+
+    fn parse(monkies: String) -> Result<Input, ParseError> {
+        monkies
+            .lines()
+            .chunks(7)
+            .into_iter()
+            .map(|chunk| match chunk.collect_vec().as_slice() {
+                &[_, items, operation, test, if_true, if_false, ..] => Ok(Monkey {
+                    items: field::<Vec<_>>(&monkies, items, "  Starting items: ", "worry levels")?
+                        .into(),
+                    operation: field(&monkies, operation, "  Operation: ", "an operation")?,
+                    test: field(&monkies, test, "  Test: divisible by", "a divisor")?,
+                    target: (
+                        field(
+                            &monkies,
+                            if_true,
+                            "    If true: throw to monkey",
+                            "a monkey",
+                        )?,
+                        field(
+                            &monkies,
+                            if_false,
+                            "    If false: throw to monkey",
+                            "a monkey",
+                        )?,
+                    ),
+                }),
+                lines => {
+                    let last = lines[lines.len() - 1];
+                    Err(ParseError::at(
+                        &monkies,
+                        &last[last.len()..],
+                        "six lines for each monkey",
+                    ))
+                }
+            })
+            .collect()
+    }
+
+    fn part1(input: &Input) -> Answer {
+        run_p1(input.clone()).into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        run_p2(input.clone(), 10000).into()
+    }
+
+    // `size` items between up to eight monkeys, with part 1's worries still fitting a `usize`
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let count = (size / 4).clamp(2, 8);
+        let mut tests = [2, 3, 5, 7, 11, 13, 17, 19];
+
+        // squaring tends to overflow, so give up on it if it keeps doing so
+        for attempt in 0.. {
+            tests.shuffle(rng);
+            let squarer = (attempt < 16).then(|| rng.gen_range(0..count));
+
+            let mut monkeys: Vec<Monkey> = (0..count)
+                .map(|i| {
+                    let operation = match rng.gen_range(0..3) {
+                        _ if squarer == Some(i) => Operation::Square,
+                        0 => Operation::Mul(rng.gen_range(2..20)),
+                        _ => Operation::Add(rng.gen_range(1..9)),
+                    };
+                    let target = loop {
+                        let (t, f) = (rng.gen_range(0..count), rng.gen_range(0..count));
+                        if t != i && f != i && (t != f || count == 2) {
+                            break (t, f);
+                        }
+                    };
+                    Monkey {
+                        items: VecDeque::from([rng.gen_range(50..100)]),
+                        operation,
+                        test: tests[i],
+                        target,
+                    }
+                })
+                .collect();
+            for _ in count..size {
+                let worry = rng.gen_range(50..100);
+                monkeys[rng.gen_range(0..count)].items.push_back(worry);
+            }
+
+            if fits(monkeys.clone()) {
+                let monkeys: Vec<String> = monkeys
+                    .iter()
+                    .enumerate()
+                    .map(|(i, monkey)| {
+                        let operation = match monkey.operation {
+                            Operation::Add(y) => format!("old + {y}"),
+                            Operation::Mul(y) => format!("old * {y}"),
+                            Operation::Square => "old * old".to_owned(),
+                        };
+                        format!(
+                            "Monkey {i}:\n  Starting items: {}\n  Operation: new = {operation}\n  \
+                         Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                         If false: throw to monkey {}\n",
+                            monkey.items.iter().join(", "),
+                            monkey.test,
+                            monkey.target.0,
+                            monkey.target.1,
+                        )
+                    })
+                    .collect();
+                return Some(monkeys.join("\n"));
+            }
+        }
+
+        unreachable!()
+    }
+
+    // the worries in full rather than modulo every test, for as many rounds as they stay
+    // small enough to work with
+    fn reference(input: &Input) -> Option<Vec<Comparison>> {
+        let mut items: Vec<VecDeque<BigUint>> = input
+            .iter()
+            .map(|monkey| monkey.items.iter().map(|&worry| worry.into()).collect())
+            .collect();
+        let mut inspections = vec![0; input.len()];
+
+        let mut rounds = 0;
+        while rounds < 10000 && items.iter().flatten().all(|worry| worry.bits() <= 1024) {
+            for (i, monkey) in input.iter().enumerate() {
+                inspections[i] += items[i].len();
+                while let Some(worry) = items[i].pop_back() {
+                    let worry = match monkey.operation {
+                        Operation::Add(y) => worry + y,
+                        Operation::Mul(y) => worry * y,
+                        Operation::Square => &worry * &worry,
+                    };
+                    let send_to = if &worry % monkey.test == BigUint::default() {
+                        monkey.target.0
+                    } else {
+                        monkey.target.1
+                    };
+                    items[send_to].push_back(worry);
+                }
+            }
+            rounds += 1;
+        }
+
+        inspections.sort();
+        inspections.reverse();
+        let reference: usize = inspections.into_iter().take(2).product();
+
+        // the same `what` however many rounds it took, so the minimiser sees one failure
+        Some(vec![Comparison {
+            what: "part 2".to_owned(),
+            reference: reference.into(),
+            solution: run_p2(input.clone(), rounds).into(),
+        }])
+    }
+}
+
+fn field<'s, T: Inpt<'s>>(
+    input: &str,
+    line: &'s str,
+    prefix: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    let Some(value) = line.strip_prefix(prefix) else {
+        return Err(ParseError::at(input, line, format!("`{}`", prefix.trim())));
+    };
+    inpt(value).map_err(|_| ParseError::at(input, value, expected))
+}
+
+fn run_p1(mut monkies: Input) -> usize {
+    let mut inspections = vec![0; monkies.len()];
+
+    for _ in 0..20 {
+        for i in 0..monkies.len() {
+            inspections[i] += monkies[i].items.len();
+            while let Some(worry) = monkies[i].items.pop_back() {
+                let worry = monkies[i].operation.apply(worry) / 3;
+                let send_to = if worry.is_multiple_of(monkies[i].test) {
+                    monkies[i].target.0
+                } else {
+                    monkies[i].target.1
+                };
+                monkies[send_to].items.push_back(worry);
+            }
+        }
+    }
+
+    inspections.sort();
+    inspections.reverse();
+    inspections.into_iter().take(2).product()
+}
+
+fn run_p2(mut monkies: Input, rounds: usize) -> usize {
+    let mut inspections = vec![0; monkies.len()];
+
+    let cm: usize = monkies.iter().map(|m| m.test).product();
+
+    for _ in 0..rounds {
+        for i in 0..monkies.len() {
+            inspections[i] += monkies[i].items.len();
+            while let Some(worry) = monkies[i].items.pop_back() {
+                let worry = monkies[i].operation.apply(worry) % cm;
+                let send_to = if worry.is_multiple_of(monkies[i].test) {
+                    monkies[i].target.0
+                } else {
+                    monkies[i].target.1
+                };
+                monkies[send_to].items.push_back(worry);
+            }
+        }
+    }
+
+    inspections.sort();
+    inspections.reverse();
+    inspections.into_iter().take(2).product()
+}
+
+// whether part 1 gets through its rounds without any worry overflowing
+fn fits(mut monkies: Input) -> bool {
+    for _ in 0..20 {
+        for i in 0..monkies.len() {
+            while let Some(worry) = monkies[i].items.pop_back() {
+                let worry = match monkies[i].operation {
+                    Operation::Add(y) => worry.checked_add(y),
+                    Operation::Mul(y) => worry.checked_mul(y),
+                    Operation::Square => worry.checked_mul(worry),
+                };
+                let Some(worry) = worry.map(|worry| worry / 3) else {
+                    return false;
+                };
+                let send_to = if worry % monkies[i].test == 0 {
+                    monkies[i].target.0
+                } else {
+                    monkies[i].target.1
+                };
+                monkies[send_to].items.push_back(worry);
+            }
+        }
+    }
+    true
+}
@@ -0,0 +1,197 @@
+use grid::Grid;
+use itertools::Itertools;
+use pathfinding::directed::astar;
+use rand::{Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Solution},
+};
+
+type Point = (usize, usize);
+
+#[derive(Debug)]
+pub struct Input {
+    start: Point,
+    goal: Point,
+    grid: Grid<usize>,
+}
+
+pub struct Day12;
+
+register!(Day12);
+
+impl Solution for Day12 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 12;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        let mut out = Input {
+            start: (0, 0),
+            goal: (0, 0),
+            grid: Grid::new(0, 0),
+        };
+
+        for (longitude, line) in input.lines().enumerate() {
+            let mut row = Vec::with_capacity(line.len());
+
+            for (latitude, (i, hill)) in line.char_indices().enumerate() {
+                row.push(match hill {
+                    'S' => {
+                        out.start = (longitude, latitude);
+                        0
+                    }
+                    'E' => {
+                        out.goal = (longitude, latitude);
+                        25
+                    }
+                    'a'..='z' => hill as usize - 'a' as usize,
+                    _ => {
+                        return Err(ParseError::at(
+                            &input,
+                            &line[i..i + hill.len_utf8()],
+                            "a height from a to z, S or E",
+                        ))
+                    }
+                });
+            }
+
+            // the grid can't take an empty row, even as the first
+            if row.is_empty() || (out.grid.cols() != 0 && row.len() != out.grid.cols()) {
+                let expected = match out.grid.cols() {
+                    0 => "a row of squares".to_owned(),
+                    cols => format!("{cols} squares"),
+                };
+                return Err(ParseError::at(&input, line, expected));
+            }
+            out.grid.push_row(row);
+        }
+
+        Ok(out)
+    }
+
+    fn part1(
+        &Input {
+            start,
+            goal,
+            ref grid,
+        }: &Input,
+    ) -> Answer {
+        let (p1, _) = astar::astar(
+            &start,
+            |center| {
+                let mut possibles = Vec::with_capacity(4);
+                if center.0 > 0 {
+                    possibles.push((center.0 - 1, center.1));
+                }
+                if center.1 > 0 {
+                    possibles.push((center.0, center.1 - 1));
+                }
+
+                possibles.push((center.0 + 1, center.1));
+                possibles.push((center.0, center.1 + 1));
+
+                possibles
+                    .into_iter()
+                    .filter_map(|n| {
+                        let &here = grid.get(center.0, center.1)?;
+                        let &there = grid.get(n.0, n.1)?;
+
+                        if here + 1 < there {
+                            return None;
+                        }
+                        Some((n, here.abs_diff(there) + 1))
+                    })
+                    .collect_vec()
+            },
+            |tail| (goal.0.abs_diff(tail.0)) + (goal.1.abs_diff(tail.1)),
+            |&tail| tail == goal,
+        )
+        .unwrap();
+
+        (p1.len() - 1).into()
+    }
+
+    fn part2(&Input { goal, ref grid, .. }: &Input) -> Answer {
+        let (p2, _) = astar::astar(
+            &goal,
+            |center| {
+                let mut possibles = Vec::with_capacity(4);
+                if center.0 > 0 {
+                    possibles.push((center.0 - 1, center.1));
+                }
+                if center.1 > 0 {
+                    possibles.push((center.0, center.1 - 1));
+                }
+
+                possibles.push((center.0 + 1, center.1));
+                possibles.push((center.0, center.1 + 1));
+
+                possibles
+                    .into_iter()
+                    .filter_map(|n| {
+                        let &here = grid.get(center.0, center.1)?;
+                        let &there = grid.get(n.0, n.1)?;
+
+                        log::trace!("{} - {} = {}", here, there, here <= there + 1);
+
+                        if there + 1 < here {
+                            return None;
+                        }
+                        Some((n, here.abs_diff(there) + 1))
+                    })
+                    .collect_vec()
+            },
+            |_| 0,
+            |n| {
+                if let Some(&h) = grid.get(n.0, n.1) {
+                    return h == 0;
+                }
+                false
+            },
+        )
+        .unwrap();
+
+        (p2.len() - 1).into()
+    }
+
+    // a map `size` squares tall and four times as wide, with one way up hidden in it
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let (rows, cols) = (size.max(2), (size * 4).max(26));
+        let mut map: Vec<Vec<char>> = (0..rows)
+            .map(|_| (0..cols).map(|_| rng.gen_range('a'..='z')).collect())
+            .collect();
+
+        // wandering north and south on the way east, climbing evenly from S to E
+        let mut row = rng.gen_range(0..rows);
+        let mut path = Vec::new();
+        for col in 0..cols {
+            let to = rng.gen_range(0..rows);
+            while row != to {
+                path.push((row, col));
+                row = if row < to { row + 1 } else { row - 1 };
+            }
+            path.push((row, col));
+        }
+        for (i, &(row, col)) in path.iter().enumerate() {
+            map[row][col] = (b'a' + (i * 25 / (path.len() - 1)) as u8) as char;
+        }
+        let ((start_row, start_col), (goal_row, goal_col)) = (path[0], path[path.len() - 1]);
+        map[start_row][start_col] = 'S';
+        map[goal_row][goal_col] = 'E';
+
+        Some(
+            map.iter()
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect(),
+        )
+    }
+}
+
+// 5+1 < 4 continue
+// 5+1 < 5 continue
+// 5+1 < 6 continue
+// 5+1 < 7 skip
@@ -0,0 +1,199 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+use nom::{
+    branch, bytes::complete as bytes, character::complete as character, combinator, multi,
+    sequence, IResult,
+};
+use rand::{Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Solution},
+};
+
+pub type Input = Vec<(PacketStream, PacketStream)>;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PacketStream {
+    Value(u32),
+    List(Vec<PacketStream>),
+}
+
+impl From<Vec<PacketStream>> for PacketStream {
+    fn from(x: Vec<PacketStream>) -> Self {
+        PacketStream::List(x)
+    }
+}
+
+impl From<u32> for PacketStream {
+    fn from(x: u32) -> Self {
+        PacketStream::Value(x)
+    }
+}
+
+impl Ord for PacketStream {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (PacketStream::Value(x), PacketStream::Value(y)) => x.cmp(y),
+            (PacketStream::List(x), PacketStream::List(y)) => x.cmp(y),
+            (&PacketStream::Value(x), y @ PacketStream::List(_)) => {
+                PacketStream::List(vec![PacketStream::Value(x)]).cmp(y)
+            }
+            (x @ PacketStream::List(_), &PacketStream::Value(y)) => {
+                x.cmp(&PacketStream::List(vec![PacketStream::Value(y)]))
+            }
+        }
+    }
+}
+
+impl PartialOrd for PacketStream {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// written back the way it was parsed
+impl Display for PacketStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PacketStream::Value(x) => write!(f, "{x}"),
+            PacketStream::List(items) => write!(f, "[{}]", items.iter().join(",")),
+        }
+    }
+}
+
+// far deeper than any real packet, but not so deep that parsing runs out of stack
+const MAX_DEPTH: usize = 64;
+
+/// Parses one packet such as `[1,[2,[3]]]`.
+pub fn packet_stream(i: &str) -> IResult<&str, PacketStream> {
+    nested_packet(i, MAX_DEPTH)
+}
+
+fn nested_packet(i: &str, depth: usize) -> IResult<&str, PacketStream> {
+    if depth == 0 {
+        return Err(nom::Err::Failure(nom::error::Error::new(
+            i,
+            nom::error::ErrorKind::TooLarge,
+        )));
+    }
+
+    combinator::map(
+        sequence::delimited(
+            bytes::tag("["),
+            multi::separated_list0(
+                bytes::tag(","),
+                branch::alt((combinator::map(character::u32, PacketStream::Value), |i| {
+                    nested_packet(i, depth - 1)
+                })),
+            ),
+            bytes::tag("]"),
+        ),
+        PacketStream::List,
+    )(i)
+}
+
+fn packet_pair(i: &str) -> IResult<&str, (PacketStream, PacketStream)> {
+    sequence::pair(
+        sequence::terminated(packet_stream, character::line_ending),
+        sequence::terminated(
+            packet_stream,
+            branch::alt((character::line_ending, combinator::eof)),
+        ),
+    )(i)
+}
+
+pub struct Day13;
+
+register!(Day13);
+
+impl Solution for Day13 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 13;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        let (rest, pairs) =
+            multi::separated_list0(character::line_ending, combinator::cut(packet_pair))(&input)
+                .map_err(|err| ParseError::nom(&input, err, "a packet like [1,[2,3]]"))?;
+        ParseError::finished(&input, rest, "a blank line between pairs of packets")?;
+
+        Ok(pairs)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        input
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (x, y))| if x < y { Some(i + 1) } else { None })
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        let p2 = input
+            .iter()
+            .flat_map(|pair| [&pair.0, &pair.1])
+            .collect_vec();
+
+        let two = PacketStream::List(vec![PacketStream::List(vec![PacketStream::Value(2)])]);
+        let six = PacketStream::List(vec![PacketStream::List(vec![PacketStream::Value(6)])]);
+
+        let two = p2.iter().filter(|&&p| p < &two).count() + 1;
+        // the [[2]] divider also sorts before [[6]]
+        let six = p2.iter().filter(|&&p| p < &six).count() + 2;
+
+        (two * six).into()
+    }
+
+    // `size` pairs of packets, the second often just a little different from the first
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let pairs = (0..size.max(1)).map(|_| {
+            let left = random_packet(rng, 4);
+            let right = match rng.gen_bool(0.7) {
+                true => tweak(rng, &left),
+                false => random_packet(rng, 4),
+            };
+            format!("{left}\n{right}\n")
+        });
+        Some(pairs.collect::<Vec<_>>().join("\n"))
+    }
+}
+
+// a list of up to five values and lists, nested at most `depth` deep
+fn random_packet(rng: &mut dyn RngCore, depth: usize) -> PacketStream {
+    let len = rng.gen_range(0..=5);
+    let items = (0..len).map(|_| match depth > 0 && rng.gen_bool(0.3) {
+        true => random_packet(rng, depth - 1),
+        false => PacketStream::Value(rng.gen_range(0..=10)),
+    });
+    PacketStream::List(items.collect())
+}
+
+// `packet` with a change somewhere, if any, so comparing them has to go deep
+fn tweak(rng: &mut dyn RngCore, packet: &PacketStream) -> PacketStream {
+    match packet {
+        &PacketStream::Value(x) => match rng.gen_range(0..4) {
+            0 => PacketStream::List(vec![PacketStream::Value(x)]),
+            1 => PacketStream::Value(x + 1),
+            2 => PacketStream::Value(x.saturating_sub(1)),
+            _ => PacketStream::Value(x),
+        },
+        PacketStream::List(items) => {
+            let mut items = items.clone();
+            match rng.gen_range(0..4) {
+                0 => drop(items.pop()),
+                1 => items.push(random_packet(rng, 1)),
+                _ if !items.is_empty() => {
+                    let i = rng.gen_range(0..items.len());
+                    items[i] = tweak(rng, &items[i]);
+                }
+                _ => {}
+            }
+            PacketStream::List(items)
+        }
+    }
+}
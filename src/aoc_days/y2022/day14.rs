@@ -0,0 +1,185 @@
+use std::{
+    cmp::{max, min},
+    collections::HashSet,
+};
+
+use nom::{bytes::complete as bytes, character::complete as character, multi, sequence, IResult};
+use rand::{Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Solution},
+    visualise::{self, Frame},
+};
+
+pub type Input = Vec<Path>;
+
+type Point = (i32, i32);
+type Path = Vec<Point>;
+
+// 499,65 -> 499,68 -> 491,68 -> 491,74 -> 508,74 -> 508,68 -> 504,68 -> 504,65
+
+fn point(i: &str) -> IResult<&str, Point> {
+    sequence::separated_pair(character::i32, bytes::tag(","), character::i32)(i)
+}
+
+fn path(i: &str) -> IResult<&str, Path> {
+    multi::separated_list1(bytes::tag(" -> "), point)(i)
+}
+
+pub struct Day14;
+
+register!(Day14);
+
+impl Solution for Day14 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 14;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        let (rest, paths) = multi::separated_list1(character::line_ending, path)(&input)
+            .map_err(|err| ParseError::nom(&input, err, "a path like `498,4 -> 498,6`"))?;
+        ParseError::finished(&input, rest, "a path like `498,4 -> 498,6`")?;
+
+        Ok(paths)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        pour(input, false).into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        pour(input, true).into()
+    }
+
+    // `size` paths of rock, reaching down about `size` deep
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let depth = size.clamp(10, 170) as i32;
+        let paths = (0..size.max(1)).map(|_| {
+            let mut at = (
+                rng.gen_range(500 - depth..=500 + depth),
+                rng.gen_range(1..=depth),
+            );
+            let mut points = vec![at];
+            let across = rng.gen_bool(0.5);
+            for turn in 0..rng.gen_range(1..=5) {
+                let step = rng.gen_range(-8..=8);
+                at = match (turn % 2 == 0) == across {
+                    true => (at.0 + step, at.1),
+                    false => (at.0, (at.1 + step).clamp(1, depth)),
+                };
+                points.push(at);
+            }
+            let points: Vec<String> = points.iter().map(|(x, y)| format!("{x},{y}")).collect();
+            points.join(" -> ")
+        });
+        Some(paths.map(|path| path + "\n").collect())
+    }
+}
+
+// without a floor, sand stops being counted once it falls past the lowest rock
+fn pour(input: &Input, floor: bool) -> usize {
+    let mut rocks = HashSet::new();
+    let mut lowest = 0;
+
+    for path in input {
+        for slice in path.windows(2) {
+            if let [a, b] = slice {
+                lowest = max(max(lowest, a.1), b.1);
+                match a.0 == b.0 {
+                    // horizontal
+                    true => {
+                        let top = min(a.1, b.1);
+                        let bot = max(a.1, b.1);
+                        rocks.extend((top..=bot).map(|y| (a.0, y)))
+                    }
+                    // vertical
+                    false => {
+                        let left = min(a.0, b.0);
+                        let right = max(a.0, b.0);
+                        rocks.extend((left..=right).map(|x| (x, a.1)))
+                    }
+                }
+            }
+        }
+    }
+
+    let mut sand = HashSet::new();
+
+    // wide enough for the pile against the floor, which reaches out as far as it is high
+    let (left, right) = match floor {
+        true => (500 - lowest - 2, 500 + lowest + 2),
+        false => (
+            rocks.iter().map(|p| p.0).min().unwrap_or(500) - 1,
+            rocks.iter().map(|p| p.0).max().unwrap_or(500) + 1,
+        ),
+    };
+    let draw = |sand: &HashSet<Point>| {
+        let mut frame = Frame::new(
+            format!("{} units of sand", sand.len()),
+            (right - left + 1) as usize,
+            (lowest + 3) as usize,
+        );
+        for y in 0..=lowest + 2 {
+            for x in left..=right {
+                let c = match (rocks.contains(&(x, y)), sand.contains(&(x, y))) {
+                    (true, _) => '#',
+                    (_, true) => 'o',
+                    _ if floor && y == lowest + 2 => '#',
+                    _ => '.',
+                };
+                frame.set((x - left) as usize, y as usize, c);
+            }
+        }
+        frame
+    };
+
+    'outer: loop {
+        let mut p = (500, 0);
+
+        'inner: loop {
+            let next = (p.0, p.1 + 1);
+
+            if next.1 == lowest + 2 {
+                if !floor {
+                    break 'outer;
+                }
+                sand.insert(p);
+                visualise::emit(|| draw(&sand));
+                break 'inner;
+            }
+
+            if !rocks.contains(&next) && !sand.contains(&next) {
+                p = next;
+                continue 'inner;
+            }
+
+            let next = (p.0 - 1, p.1 + 1);
+
+            if !rocks.contains(&next) && !sand.contains(&next) {
+                p = next;
+                continue 'inner;
+            }
+
+            let next = (p.0 + 1, p.1 + 1);
+
+            if !rocks.contains(&next) && !sand.contains(&next) {
+                p = next;
+                continue 'inner;
+            }
+
+            sand.insert(p);
+            visualise::emit(|| draw(&sand));
+
+            if p.1 == 0 {
+                break 'outer;
+            }
+
+            break 'inner;
+        }
+    }
+
+    sand.len()
+}
@@ -0,0 +1,275 @@
+use std::collections::HashSet;
+
+use inpt::{inpt, Inpt};
+use itertools::Itertools;
+use rand::{Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Comparison, Solution},
+};
+
+#[derive(Debug, Inpt, Copy, Clone)]
+#[inpt(regex = r"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)")]
+pub struct Reading {
+    s_x: i64,
+    s_y: i64,
+    b_x: i64,
+    b_y: i64,
+    #[inpt(skip)]
+    distance: u64,
+}
+
+impl Reading {
+    fn range_on_row(&self, row: i64) -> Option<(i64, i64)> {
+        let dist = self.s_y.abs_diff(row);
+        if dist > self.distance {
+            return None;
+        }
+
+        let spread = (self.distance - dist) as i64;
+        Some(((self.s_x - spread), (self.s_x + spread)))
+    }
+}
+
+pub type Input = Vec<Reading>;
+
+const P1_ROW: i64 = 2000000;
+const P2_START: i64 = 0;
+const P2_END: i64 = 4000000;
+
+// how many places along `row` can't hold a beacon
+fn covered_on_row(input: &Input, row: i64) -> usize {
+    let mut covered = HashSet::new();
+    for sr in input {
+        if let Some(range) = sr.range_on_row(row) {
+            covered.extend(range.0..=range.1);
+        }
+    }
+
+    for &Reading { b_x, b_y, .. } in input {
+        if b_y == row {
+            covered.remove(&b_x);
+        }
+    }
+
+    covered.len()
+}
+
+// the first point in reading order within `P2_START..=bound` both ways that no sensor
+// can see
+fn distress_beacon(input: &Input, bound: i64) -> Option<(i64, i64)> {
+    let mut y = P2_START;
+    while y <= bound {
+        let mut ranges = input
+            .iter()
+            .filter_map(|sr| sr.range_on_row(y))
+            .collect_vec();
+        ranges.sort();
+
+        // covered up to and including `here`
+        let mut here = P2_START - 1;
+        // how many more rows the ranges that cover this one surely cover too, as each end
+        // moves by at most one a row and a range lasts at least as many rows as it spreads
+        let mut covered = i64::MAX;
+        for &(s, e) in &ranges {
+            if here + 1 < s || here >= bound {
+                break;
+            }
+            if e > here {
+                covered = covered.min((here + 1 - s) / 2).min((e - s) / 2);
+                here = e;
+            }
+        }
+
+        if here < bound {
+            return Some((here + 1, y));
+        }
+        y += covered.min(here - bound) + 1;
+    }
+
+    None
+}
+
+fn tuning_frequency(beacon: Option<(i64, i64)>) -> Answer {
+    match beacon {
+        Some((x, y)) => (x * 4000000 + y).into(),
+        None => Answer::Unsolved,
+    }
+}
+
+// `input` moved `dx` right and `dy` down
+fn shifted(input: &Input, dx: i64, dy: i64) -> Input {
+    input
+        .iter()
+        .map(|sr| Reading {
+            s_x: sr.s_x + dx,
+            s_y: sr.s_y + dy,
+            b_x: sr.b_x + dx,
+            b_y: sr.b_y + dy,
+            ..*sr
+        })
+        .collect()
+}
+
+pub struct Day15;
+
+register!(Day15);
+
+impl Solution for Day15 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 15;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        let mut sensor_readings = input
+            .lines()
+            .map(|s| {
+                inpt::<Reading>(s).map_err(|_| {
+                    ParseError::at(
+                        &input,
+                        s,
+                        "a line like `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`",
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        sensor_readings.iter_mut().for_each(|sr| {
+            sr.distance = sr.s_x.abs_diff(sr.b_x) + sr.s_y.abs_diff(sr.b_y);
+        });
+
+        Ok(sensor_readings)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        covered_on_row(input, P1_ROW).into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        tuning_frequency(distress_beacon(input, P2_END))
+    }
+
+    // `size` sensors, about half of them reaching the row part 1 looks along
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let reach = (P2_END / size.max(1) as i64).clamp(10, 1_000_000);
+        let readings = (0..size.max(1)).map(|_| {
+            let distance = rng.gen_range(1..=reach);
+            let s_x = rng.gen_range(P2_START..=P2_END);
+            let s_y = P1_ROW + rng.gen_range(-2 * distance..=2 * distance);
+            // sometimes the beacon is on that row, where part 1 mustn't count it
+            let dy = match (P1_ROW - s_y).abs() <= distance && rng.gen_bool(0.2) {
+                true => P1_ROW - s_y,
+                false => rng.gen_range(-distance..=distance),
+            };
+            let dx = (distance - dy.abs()) * if rng.gen_bool(0.5) { 1 } else { -1 };
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                s_x,
+                s_y,
+                s_x + dx,
+                s_y + dy
+            )
+        });
+        Some(readings.collect())
+    }
+
+    // every cell along the row in turn, checked against every sensor and beacon, then
+    // every point of a small square just past each sensor's reach for part 2
+    fn reference(input: &Input) -> Option<Vec<Comparison>> {
+        let mut comparisons = Vec::new();
+
+        let left = input.iter().map(|sr| sr.s_x - sr.distance as i64).min();
+        let right = input.iter().map(|sr| sr.s_x + sr.distance as i64).max();
+        let (left, right) = (left.unwrap_or(0), right.unwrap_or(-1));
+        if (right - left).saturating_mul(input.len() as i64) <= 200_000_000 {
+            let covered = (left..=right)
+                .filter(|&x| {
+                    let seen = input
+                        .iter()
+                        .any(|sr| sr.s_x.abs_diff(x) + sr.s_y.abs_diff(P1_ROW) <= sr.distance);
+                    let beacon = input.iter().any(|sr| (sr.b_x, sr.b_y) == (x, P1_ROW));
+                    seen && !beacon
+                })
+                .count();
+
+            comparisons.push(Comparison {
+                what: "part 1".to_owned(),
+                reference: covered.into(),
+                solution: Self::part1(input),
+            });
+        }
+
+        // the whole of part 2's square is far too big, so the input is moved to put the
+        // edge of each sensor's reach in the middle of a small one instead
+        const SIDE: i64 = 40;
+        for sr in input {
+            let moved = shifted(
+                input,
+                SIDE / 2 - (sr.s_x + sr.distance as i64 + 1),
+                SIDE / 2 - sr.s_y,
+            );
+            let unseen = (P2_START..=SIDE)
+                .flat_map(|y| (P2_START..=SIDE).map(move |x| (x, y)))
+                .find(|&(x, y)| {
+                    moved
+                        .iter()
+                        .all(|sr| sr.s_x.abs_diff(x) + sr.s_y.abs_diff(y) > sr.distance)
+                });
+
+            comparisons.push(Comparison {
+                // not which sensor, as dropping one while minimising renumbers the rest
+                what: "part 2".to_owned(),
+                reference: tuning_frequency(unseen),
+                solution: tuning_frequency(distress_beacon(&moved, SIDE)),
+            });
+        }
+
+        Some(comparisons)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{covered_on_row, distress_beacon, tuning_frequency, Day15, Input};
+    use crate::solution::Solution;
+
+    // the puzzle's example looks along row 10 and searches up to 20 rather than the real
+    // input's row and bound
+    const EXAMPLE: &str = include_str!("../../../examples/2022/day15/example.txt");
+
+    #[test]
+    fn example() {
+        let input = Day15::parse(EXAMPLE.to_owned()).unwrap();
+
+        assert_eq!(covered_on_row(&input, 10), 26);
+        assert_eq!(distress_beacon(&input, 20), Some((14, 11)));
+        assert_eq!(
+            tuning_frequency(distress_beacon(&input, 20)),
+            56000011.into()
+        );
+    }
+
+    fn sensors(lines: &[&str]) -> Input {
+        Day15::parse(lines.concat()).unwrap()
+    }
+
+    #[test]
+    fn ranges_that_touch_leave_no_gap() {
+        // on row 0 the first sees -1..=1 and the second 2..=4
+        let input = sensors(&[
+            "Sensor at x=0, y=1: closest beacon is at x=0, y=-1\n",
+            "Sensor at x=3, y=1: closest beacon is at x=3, y=3\n",
+        ]);
+
+        assert_eq!(distress_beacon(&input, 2), None);
+    }
+
+    #[test]
+    fn the_first_column_can_be_the_gap() {
+        let input = sensors(&["Sensor at x=3, y=1: closest beacon is at x=3, y=3\n"]);
+
+        assert_eq!(distress_beacon(&input, 2), Some((0, 0)));
+    }
+}
@@ -0,0 +1,339 @@
+use std::{
+    cmp::{max, min},
+    collections::HashMap,
+};
+
+use grid::Grid;
+use itertools::Itertools;
+use nom::IResult;
+use rand::{seq::SliceRandom, Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    generate,
+    parse_error::ParseError,
+    solution::{Answer, Comparison, Solution},
+};
+
+pub type Input = Graph;
+
+#[derive(Debug)]
+pub struct Graph {
+    start: usize,
+    flows: Vec<i32>,
+    costs: Grid<i32>,
+}
+
+#[derive(Debug)]
+pub struct RoomDesc {
+    label: String,
+    flow_rate: i32,
+    connections: Vec<String>,
+}
+
+fn parse_room(i: &str) -> IResult<&str, RoomDesc> {
+    use nom::{
+        branch::alt,
+        bytes::complete::{tag, take},
+        character::complete::i32,
+        combinator::map,
+        multi::separated_list1,
+        sequence::tuple,
+    };
+
+    map(
+        tuple((
+            tag::<_, &str, _>("Valve "),
+            map(take(2_usize), |s: &str| s.to_string()),
+            tag(" has flow rate="),
+            i32,
+            alt((
+                tag("; tunnels lead to valves "),
+                tag("; tunnel leads to valve "),
+            )),
+            separated_list1(tag(", "), map(take(2_usize), |s: &str| s.to_string())),
+        )),
+        |(_, label, _, flow_rate, _, connections)| RoomDesc {
+            label,
+            flow_rate,
+            connections,
+        },
+    )(i)
+}
+
+pub struct Day16;
+
+register!(Day16);
+
+impl Solution for Day16 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 16;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        let lines = input.lines().collect_vec();
+        let rooms = lines
+            .iter()
+            .map(|line| {
+                let (rest, room) = parse_room(line)
+                    .map_err(|err| ParseError::nom(&input, err, "a valve description"))?;
+                ParseError::finished(&input, rest, "the end of the line")?;
+                Ok(room)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let label_idx: HashMap<_, _> = rooms
+            .iter()
+            .enumerate()
+            .map(|(i, r)| (r.label.clone(), i))
+            .collect();
+
+        let mut wg: Grid<i32> = Grid::init(rooms.len(), rooms.len(), (rooms.len() as i32) * 2);
+
+        for (line, src) in lines.iter().zip(&rooms) {
+            for dst in &src.connections {
+                let Some(&to) = label_idx.get(dst) else {
+                    let at = line.rfind(dst.as_str()).unwrap_or_default();
+                    return Err(ParseError::at(
+                        &input,
+                        &line[at..at + dst.len()],
+                        "a valve that exists",
+                    ));
+                };
+                wg[label_idx[&src.label]][to] = 1;
+            }
+        }
+
+        for i in 0..rooms.len() {
+            wg[i][i] = 0;
+        }
+
+        for k in 0..rooms.len() {
+            for i in 0..rooms.len() {
+                for j in 0..rooms.len() {
+                    wg[i][j] = min(wg[i][j], wg[i][k] + wg[k][j]);
+                }
+            }
+        }
+
+        let Some(&start) = label_idx.get("AA") else {
+            return Err(ParseError::at(&input, &input[..0], "a valve labelled AA"));
+        };
+
+        Ok(Graph {
+            start,
+            flows: rooms.iter().map(|r| r.flow_rate).collect(),
+            costs: wg,
+        })
+    }
+
+    fn part1(g: &Input) -> Answer {
+        let p1_worker = Worker {
+            to: g.start,
+            time: 30,
+        };
+        dfs_single(g, valves(g), p1_worker).into()
+    }
+
+    fn part2(g: &Input) -> Answer {
+        let p2_worker = Worker {
+            to: g.start,
+            time: 26,
+        };
+        double_runner(g, valves(g), p2_worker, p2_worker).into()
+    }
+
+    // `size` valves, at most fifteen of them with any flow as in the real ones
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let count = size.clamp(2, 26 * 26);
+        let labels = generate::names(rng, count - 1, 2, &["aa"])
+            .into_iter()
+            .map(|label| label.to_uppercase());
+        let labels: Vec<String> = ["AA".to_owned()].into_iter().chain(labels).collect();
+
+        // a tree so every valve can be reached, with some shortcuts through it
+        let mut tunnels: Vec<Vec<usize>> = vec![Vec::new(); count];
+        let mut dig = |a: usize, b: usize| {
+            if a != b && !tunnels[a].contains(&b) {
+                tunnels[a].push(b);
+                tunnels[b].push(a);
+            }
+        };
+        for i in 1..count {
+            dig(i, rng.gen_range(0..i));
+        }
+        for _ in 0..count / 2 {
+            dig(rng.gen_range(0..count), rng.gen_range(0..count));
+        }
+
+        let mut flows = vec![0; count];
+        let mut flowing: Vec<usize> = (1..count).collect();
+        flowing.shuffle(rng);
+        for &valve in flowing.iter().take(15) {
+            flows[valve] = rng.gen_range(1..=25);
+        }
+
+        let mut valves: Vec<String> = (0..count)
+            .map(|i| {
+                let to = tunnels[i].iter().map(|&t| &labels[t]).join(", ");
+                let tunnels = match tunnels[i].len() {
+                    1 => "tunnel leads to valve",
+                    _ => "tunnels lead to valves",
+                };
+                format!(
+                    "Valve {} has flow rate={}; {} {}\n",
+                    labels[i], flows[i], tunnels, to
+                )
+            })
+            .collect();
+        valves.shuffle(rng);
+        Some(valves.concat())
+    }
+
+    // every move and every valve opened, a minute at a time, which only finishes on tiny
+    // graphs
+    fn reference(g: &Input) -> Option<Vec<Comparison>> {
+        if g.flows.len() > 8 {
+            return Some(Vec::new());
+        }
+
+        Some(vec![
+            Comparison {
+                what: "part 1".to_owned(),
+                reference: exhaustive(g, 1, 30).into(),
+                solution: Self::part1(g),
+            },
+            Comparison {
+                what: "part 2".to_owned(),
+                reference: exhaustive(g, 2, 26).into(),
+                solution: Self::part2(g),
+            },
+        ])
+    }
+}
+
+// all valves with flow
+fn valves(g: &Graph) -> Vec<usize> {
+    g.flows
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &fr)| (fr != 0).then_some(i))
+        .collect_vec()
+}
+
+fn dfs_single(g: &Graph, available: Vec<usize>, Worker { to: here, time }: Worker) -> i32 {
+    let pressure = g.flows[here] * time;
+    let mut pressures = Vec::with_capacity(available.len());
+
+    for &next in &available {
+        if g.costs[here][next] > time {
+            continue;
+        };
+        let worker = Worker {
+            to: next,
+            time: time - g.costs[here][next] - 1,
+        };
+
+        let available = available
+            .iter()
+            .copied()
+            .filter(|&x| x != next)
+            .collect_vec();
+
+        pressures.push(dfs_single(g, available, worker));
+    }
+
+    pressure + pressures.into_iter().max().unwrap_or(0)
+}
+
+// the most pressure `workers` can release in `minutes`, trying everything each of them
+// could do every minute: wait, walk a tunnel or open the valve they're at
+fn exhaustive(g: &Graph, workers: usize, minutes: i32) -> i32 {
+    // (where everyone is, which valves are open) => pressure released by the end
+    let mut states: HashMap<(Vec<usize>, u64), i32> = [((vec![g.start; workers], 0), 0)].into();
+
+    for minute in 1..=minutes {
+        let mut next = HashMap::new();
+
+        for ((at, open), released) in states {
+            let mut moves = vec![(Vec::new(), open, released)];
+            for &here in &at {
+                moves = moves
+                    .into_iter()
+                    .flat_map(|(to, open, released)| {
+                        let mut steps = vec![(here, open, released)];
+                        for there in 0..g.flows.len() {
+                            if g.costs[here][there] == 1 {
+                                steps.push((there, open, released));
+                            }
+                        }
+                        if g.flows[here] > 0 && open & 1 << here == 0 {
+                            let released = released + g.flows[here] * (minutes - minute);
+                            steps.push((here, open | 1 << here, released));
+                        }
+                        steps.into_iter().map(move |(there, open, released)| {
+                            let mut to = to.clone();
+                            to.push(there);
+                            (to, open, released)
+                        })
+                    })
+                    .collect();
+            }
+
+            for (mut to, open, released) in moves {
+                // who is where doesn't matter, only that someone is
+                to.sort();
+                let best = next.entry((to, open)).or_insert(released);
+                *best = max(*best, released);
+            }
+        }
+
+        states = next;
+    }
+
+    states.into_values().max().unwrap_or(0)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Worker {
+    to: usize,
+    time: i32,
+}
+
+fn double_runner(
+    g: &Graph,
+    available: Vec<usize>,
+    Worker { to: here, time }: Worker,
+    worker_2: Worker,
+) -> i32 {
+    let pressure = g.flows[here] * time;
+
+    let mut pressures = Vec::with_capacity(available.len());
+
+    for &next in &available {
+        if g.costs[here][next] >= time {
+            continue;
+        };
+        let worker_1 = Worker {
+            to: next,
+            time: time - g.costs[here][next] - 1,
+        };
+
+        let available = available
+            .iter()
+            .copied()
+            .filter(|&x| x != next)
+            .collect_vec();
+
+        pressures.push(if worker_1.time > worker_2.time {
+            double_runner(g, available, worker_1, worker_2)
+        } else {
+            double_runner(g, available, worker_2, worker_1)
+        })
+    }
+
+    pressures.push(dfs_single(g, available, worker_2));
+
+    pressure + pressures.into_iter().max().unwrap_or(0)
+}
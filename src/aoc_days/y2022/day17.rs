@@ -0,0 +1,204 @@
+use std::cmp::max;
+
+use itertools::Itertools;
+use rand::{Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Part, Solution},
+    visualise::{self, Frame},
+};
+
+pub type Input = Vec<Direction>;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    L,
+    R,
+}
+
+pub struct Day17;
+
+register!(Day17);
+
+impl Solution for Day17 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 17;
+    const PARTS: &'static [Part] = &[Part::One];
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        input
+            .trim_end()
+            .char_indices()
+            .map(|(i, c)| match c {
+                '<' => Ok(Direction::L),
+                '>' => Ok(Direction::R),
+                _ => Err(ParseError::at(
+                    &input,
+                    &input[i..i + c.len_utf8()],
+                    "`<` or `>`",
+                )),
+            })
+            .collect()
+    }
+
+    fn part1(input: &Input) -> Answer {
+        let mut jets = input.iter().copied().cycle();
+        let blocks = [Shape::H, Shape::C, Shape::L, Shape::V, Shape::B]
+            .into_iter()
+            .cycle();
+
+        let mut g: Vec<u8> = Vec::new();
+        let mut tallest: usize = 0;
+
+        for block in blocks.take(2) {
+            // require at least tallest + shape.height + 3 rows
+            while g.len() < tallest + block.height() + 3 {
+                g.push(1 << 7);
+            }
+
+            tallest = max(tallest, block.sim_drop(&mut g, &mut jets, 2, tallest + 3));
+        }
+
+        visualise::emit(|| Frame::from_text(format!("{tallest} rows"), &render(g)));
+
+        tallest.into()
+    }
+
+    fn part2(_input: &Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    // `size` jets, blowing in runs of up to the chamber's width
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let size = size.max(1);
+        let mut jets = String::with_capacity(size + 1);
+        while jets.len() < size {
+            let jet = if rng.gen_bool(0.5) { '<' } else { '>' };
+            jets.extend(std::iter::repeat_n(jet, rng.gen_range(1..=7)));
+        }
+        jets.truncate(size);
+        jets.push('\n');
+        Some(jets)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Shape {
+    // *###
+    H,
+    //  #
+    // ###
+    // *#
+    C,
+    //   #
+    //   #
+    // *##
+    L,
+    // #
+    // #
+    // #
+    // *
+    V,
+    // ##
+    // *#
+    B,
+}
+
+impl Shape {
+    fn height(&self) -> usize {
+        match self {
+            Shape::H => 1,
+            Shape::C => 3,
+            Shape::L => 3,
+            Shape::V => 4,
+            Shape::B => 2,
+        }
+    }
+
+    fn width(&self) -> usize {
+        match self {
+            Shape::H => 4,
+            Shape::C => 3,
+            Shape::L => 3,
+            Shape::V => 1,
+            Shape::B => 2,
+        }
+    }
+
+    fn cells(&self, x: usize) -> Vec<u8> {
+        match self {
+            Shape::H => vec![0b1111 << x],
+            Shape::C => vec![0b010 << x, 0b111 << x, 0b010 << x],
+            Shape::L => vec![0b111 << x, 0b100 << x, 0b100 << x],
+            Shape::V => vec![0b1 << x, 0b1 << x, 0b1 << x, 0b1 << x],
+            Shape::B => vec![0b11 << x, 0b11 << x],
+        }
+    }
+
+    fn stamp(&self, g: &mut [u8], x: usize, y: usize) {
+        let rows = g[y..(y + self.height())].iter_mut();
+        let cells = self.cells(x).into_iter();
+        rows.zip(cells).for_each(|(x, y)| *x |= y);
+    }
+
+    fn is_blocked(&self, g: &[u8], x: usize, y: usize) -> bool {
+        let rows = g[y..(y + self.height())].iter();
+        let cells = self.cells(x).into_iter();
+        rows.zip(cells).any(|(x, y)| (x & y) != 0)
+    }
+
+    fn sim_drop<I: Iterator<Item = Direction>>(
+        self,
+        g: &mut [u8],
+        jets: &mut I,
+        mut x: usize,
+        mut y: usize,
+    ) -> usize {
+        for dir in jets.by_ref() {
+            visualise::emit(|| {
+                let mut h = g.to_vec();
+                self.stamp(&mut h, x, y);
+                Frame::from_text(format!("{:?} at {},{}", dir, x, y), &render(h))
+            });
+
+            match dir {
+                Direction::L => {
+                    if (x > 0) && !self.is_blocked(g, x - 1, y) {
+                        x -= 1;
+                    }
+                }
+                Direction::R => {
+                    if ((x + 1 + self.width()) <= 7) && !self.is_blocked(g, x + 1, y) {
+                        x += 1;
+                    }
+                }
+            }
+
+            if y == 0 || self.is_blocked(g, x, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+
+        self.stamp(g, x, y);
+
+        y + self.height()
+    }
+}
+
+// fn stamp_shape(g: Grid<bool>, (x, y): (usize, usize))
+
+// the chamber top down, rows of `#` and `.`
+fn render(mut g: Vec<u8>) -> String {
+    g.reverse();
+
+    g.into_iter()
+        .map(|row| format!("{:>08b}", row.reverse_bits()))
+        .join("\n")
+        .replace('0', ".")
+        .replace('1', "#")
+}
@@ -0,0 +1,95 @@
+use rand::{Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Solution},
+};
+
+pub type Input = Vec<(i32, i32)>;
+
+const ROCK: i32 = 1;
+const PAPER: i32 = 2;
+const SCISSORS: i32 = 3;
+
+const LOSE: i32 = 1;
+const DRAW: i32 = 2;
+const WIN: i32 = 3;
+
+pub struct Day2;
+
+register!(Day2);
+
+impl Solution for Day2 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 2;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        input
+            .lines()
+            .map(|s| {
+                let shape = |at: usize, options: [&str; 3]| {
+                    let found = s.get(at..at + 1).unwrap_or(&s[s.len()..]);
+                    match options.iter().position(|&o| o == found) {
+                        Some(i) => Ok(ROCK + i as i32),
+                        None => Err(ParseError::at(&input, found, options.join(", "))),
+                    }
+                };
+
+                Ok((shape(0, ["A", "B", "C"])?, shape(2, ["X", "Y", "Z"])?))
+            })
+            .collect()
+    }
+
+    fn part1(rounds: &Input) -> Answer {
+        rounds
+            .iter()
+            .map(|&(opponent, me)| match me - opponent {
+                // Lose: R - P, P - S, S - R
+                -1 | 2 => me,
+                // Draw
+                0 => 3 + me,
+                // Win: R - S, P - R, S - P
+                -2 | 1 => 6 + me,
+                _ => 0,
+            })
+            .sum::<i32>()
+            .into()
+    }
+
+    fn part2(rounds: &Input) -> Answer {
+        rounds
+            .iter()
+            .map(|&(opponent, goal)| match goal {
+                LOSE => match opponent {
+                    ROCK => SCISSORS,
+                    PAPER => ROCK,
+                    SCISSORS => PAPER,
+                    _ => 0,
+                },
+                DRAW => 3 + opponent,
+                WIN => {
+                    6 + match opponent {
+                        ROCK => PAPER,
+                        PAPER => SCISSORS,
+                        SCISSORS => ROCK,
+                        _ => 0,
+                    }
+                }
+                _ => 0,
+            })
+            .sum::<i32>()
+            .into()
+    }
+
+    // `size` rounds
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let rounds = (0..size).map(|_| {
+            let (theirs, ours) = (rng.gen_range(0..3), rng.gen_range(0..3));
+            format!("{} {}\n", ["A", "B", "C"][theirs], ["X", "Y", "Z"][ours])
+        });
+        Some(rounds.collect())
+    }
+}
@@ -0,0 +1,350 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::{Add, Div, Mul, Sub},
+};
+
+use inpt::{inpt, Inpt};
+use rand::{seq::SliceRandom, Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    generate,
+    parse_error::ParseError,
+    solution::{Answer, Comparison, Solution},
+};
+
+#[derive(Debug, Inpt, Clone, Copy)]
+pub enum Op {
+    #[inpt(regex = r"\+")]
+    Add,
+    #[inpt(regex = r"-")]
+    Sub,
+    #[inpt(regex = r"\*")]
+    Mul,
+    #[inpt(regex = r"/")]
+    Div,
+}
+
+#[derive(Debug, Inpt, Clone)]
+pub enum Job {
+    #[inpt(regex = r"(\d+)")]
+    Shout(i64),
+    #[inpt(regex = r"(\pL{4}) (.) (\pL{4})")]
+    Calc { left: String, op: Op, right: String },
+}
+
+#[derive(Debug, Inpt, Clone)]
+#[inpt(regex = r"(\pL{4}): (.*)")]
+pub struct Monkey {
+    pub name: String,
+    pub job: Job,
+}
+
+pub type Input = Vec<Monkey>;
+
+// how far the reference looks for what `humn` shouts
+const HUMN_SEARCH: i64 = 100_000;
+
+pub struct Day21;
+
+register!(Day21);
+
+impl Solution for Day21 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 21;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        input
+            .lines()
+            .map(|s| {
+                inpt(s).map_err(|_| {
+                    ParseError::at(&input, s, "a monkey like `root: pppw + sjmn` or `dbpl: 5`")
+                })
+            })
+            .collect()
+    }
+
+    fn part1(input: &Input) -> Answer {
+        let jobs = jobs(input);
+
+        calc_monkey::<i64>(&jobs, &mut HashMap::new(), "root").into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        let mut jobs = jobs(input);
+
+        match jobs.get_mut("root").unwrap() {
+            Job::Shout(_) => panic!(),
+            Job::Calc { op, .. } => *op = Op::Sub,
+        }
+
+        let mut memo: HashMap<String, FloatingMonkey> =
+            [("humn".to_owned(), FloatingMonkey([(1, 1_f64)].into()))].into();
+        let (left, right) = match jobs.get("root").unwrap() {
+            Job::Shout(_) => panic!(),
+            Job::Calc { left, right, .. } => (left, right),
+        };
+        let left = calc_monkey(&jobs, &mut memo, left);
+        let right = calc_monkey(&jobs, &mut memo, right);
+
+        let root = left - right;
+        // k * x + c = 0 => x = -c/k
+        let p2 = -root.0[&0] / root.0[&1];
+        (p2.round() as i64).into()
+    }
+
+    // `size` monkeys with `humn` somewhere down one side of `root`; every division comes
+    // out even and part 2's answer is a whole number
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let size = size.max(6);
+        let mut names = generate::names(rng, size - 2, 4, &["root", "humn"]);
+        let mut jobs = Vec::with_capacity(size);
+
+        let split = rng.gen_range(1..=size - 4);
+        let humn = Some(rng.gen_range(1..=1000));
+        let (with, value) = random_monkey(rng, &mut names, &mut jobs, split, humn);
+        let (other, other_value) =
+            random_monkey(rng, &mut names, &mut jobs, size - 3 - split, None);
+
+        // evening out the other side, so `humn` shouting what it does already is the answer
+        let (balance, shout) = (names.pop().unwrap(), names.pop().unwrap());
+        let op = if value >= other_value { '+' } else { '-' };
+        jobs.push(format!("{balance}: {other} {op} {shout}"));
+        jobs.push(format!("{shout}: {}", value.abs_diff(other_value)));
+
+        let (left, right) = match rng.gen_bool(0.5) {
+            true => (with, balance),
+            false => (balance, with),
+        };
+        jobs.push(format!("root: {left} + {right}"));
+        jobs.shuffle(rng);
+
+        Some(jobs.iter().map(|job| format!("{job}\n")).collect())
+    }
+
+    // everything `humn` could shout in turn, until both sides of `root` come out equal
+    fn reference(input: &Input) -> Option<Vec<Comparison>> {
+        let jobs = jobs(input);
+        let Some(Job::Calc { left, right, .. }) = jobs.get("root") else {
+            return Some(Vec::new());
+        };
+
+        let humn = (0..=HUMN_SEARCH).find(|&humn| {
+            let mut memo = [("humn".to_owned(), Exact::from(humn))].into();
+            let left = calc_monkey(&jobs, &mut memo, left);
+            let right = calc_monkey(&jobs, &mut memo, right);
+            left.0.is_some() && left == right
+        });
+
+        Some(
+            humn.map(|humn| Comparison {
+                what: "part 2".to_owned(),
+                reference: humn.into(),
+                solution: Self::part2(input),
+            })
+            .into_iter()
+            .collect(),
+        )
+    }
+}
+
+/// Every monkey's job, by name.
+pub fn jobs(input: &Input) -> HashMap<String, Job> {
+    let mut jobs: HashMap<String, Job> = HashMap::new();
+
+    for Monkey { name, job } in input.iter().cloned() {
+        jobs.insert(name, job);
+    }
+
+    jobs
+}
+
+/// What the monkey called `key` shouts, memoised in `memo`.
+pub fn calc_monkey<
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + From<i64> + Clone,
+>(
+    jobs: &HashMap<String, Job>,
+    memo: &mut HashMap<String, T>,
+    key: &str,
+) -> T {
+    if let Some(x) = memo.get(key) {
+        return x.clone();
+    }
+
+    let job = jobs.get(key).unwrap().clone();
+    match job {
+        Job::Shout(x) => x.into(),
+        Job::Calc { left, op, right } => {
+            let left = calc_monkey(jobs, memo, &left);
+            let right = calc_monkey(jobs, memo, &right);
+            let val = match op {
+                Op::Add => left + right,
+                Op::Sub => left - right,
+                Op::Mul => left * right,
+                Op::Div => left / right,
+            };
+            memo.insert(key.to_owned(), val.clone());
+            val
+        }
+    }
+}
+
+/// A polynomial in what `humn` shouts, for working back from `root`.
+// {pow: k} => sum(k * humn^pow)
+#[derive(Debug, Default, Clone)]
+pub struct FloatingMonkey(pub BTreeMap<u32, f64>);
+
+impl From<i64> for FloatingMonkey {
+    fn from(x: i64) -> Self {
+        FloatingMonkey([(0, x as f64)].into())
+    }
+}
+
+impl std::ops::Add for FloatingMonkey {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut out = self.clone();
+        for (pow, k) in rhs.0 {
+            *out.0.entry(pow).or_default() += k;
+        }
+        out
+    }
+}
+
+impl std::ops::Sub for FloatingMonkey {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut out = self.clone();
+        for (pow, k) in rhs.0 {
+            *out.0.entry(pow).or_default() -= k;
+        }
+        out
+    }
+}
+
+impl std::ops::Mul for FloatingMonkey {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut out = Self::default();
+        for (kl, vl) in self.0 {
+            for (kr, vr) in &rhs.0 {
+                *out.0.entry(kl + kr).or_default() += vl * vr;
+            }
+        }
+        out
+    }
+}
+
+impl std::ops::Div for FloatingMonkey {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let mut out = Self::default();
+        for (kl, vl) in self.0 {
+            for (kr, vr) in &rhs.0 {
+                *out.0.entry(kl - kr).or_default() += vl / vr;
+            }
+        }
+        out
+    }
+}
+
+/// A whole number worked out exactly, or `None` once it overflows or a division leaves
+/// a remainder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Exact(pub Option<i128>);
+
+impl From<i64> for Exact {
+    fn from(x: i64) -> Self {
+        Exact(Some(x.into()))
+    }
+}
+
+impl std::ops::Add for Exact {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Exact(self.0.zip(rhs.0).and_then(|(l, r)| l.checked_add(r)))
+    }
+}
+
+impl std::ops::Sub for Exact {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Exact(self.0.zip(rhs.0).and_then(|(l, r)| l.checked_sub(r)))
+    }
+}
+
+impl std::ops::Mul for Exact {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Exact(self.0.zip(rhs.0).and_then(|(l, r)| l.checked_mul(r)))
+    }
+}
+
+impl std::ops::Div for Exact {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Exact(self.0.zip(rhs.0).and_then(|(l, r)| {
+            l.checked_rem(r)
+                .filter(|&rem| rem == 0)
+                .and_then(|_| l.checked_div(r))
+        }))
+    }
+}
+
+// a monkey that needs about `count` monkeys to work out what it shouts, adding their jobs
+// to `jobs`, and what it shouts; `humn` shouts its number somewhere below if given one
+fn random_monkey(
+    rng: &mut dyn RngCore,
+    names: &mut Vec<String>,
+    jobs: &mut Vec<String>,
+    count: usize,
+    humn: Option<i64>,
+) -> (String, i64) {
+    if count < 3 {
+        let (name, value) = match humn {
+            Some(value) => ("humn".to_owned(), value),
+            None => (names.pop().unwrap(), rng.gen_range(1..=20)),
+        };
+        jobs.push(format!("{name}: {value}"));
+        return (name, value);
+    }
+
+    let left_count = rng.gen_range(1..count - 1);
+    let humn_left = rng.gen_bool(0.5);
+    let (left, l) = random_monkey(rng, names, jobs, left_count, humn.filter(|_| humn_left));
+    let (right, r) = random_monkey(
+        rng,
+        names,
+        jobs,
+        count - 1 - left_count,
+        humn.filter(|_| !humn_left),
+    );
+
+    // never times zero or divided by `humn`, either would lose it for part 2
+    let mut ops = vec![('+', l + r), ('-', l - r)];
+    if let Some(product) = l
+        .checked_mul(r)
+        .filter(|&p| p != 0 && p.abs() <= 1_000_000_000)
+    {
+        ops.push(('*', product));
+    }
+    let divisible = r != 0 && l % r == 0 && (humn.is_none() || humn_left);
+    let (op, value) = match divisible && rng.gen_bool(0.5) {
+        true => ('/', l / r),
+        false => *ops.choose(rng).unwrap(),
+    };
+
+    let name = names.pop().unwrap();
+    jobs.push(format!("{name}: {left} {op} {right}"));
+    (name, value)
+}
@@ -6,30 +6,61 @@ use std::{
 };
 
 use itertools::Itertools;
+use rand::{Rng, RngCore};
 
-type Input = HashSet<Point>;
-
-pub fn parse(input: String) -> Result<Input, get_inputs::Error> {
-    Ok(input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars().enumerate().filter_map(move |(x, c)| {
-                (c == '#').then_some(Point {
-                    x: x as i64,
-                    y: y as i64,
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Solution},
+    visualise::{self, Frame},
+};
+
+pub type Input = HashSet<Point>;
+
+pub struct Day23;
+
+register!(Day23);
+
+impl Solution for Day23 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 23;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        Ok(input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars().enumerate().filter_map(move |(x, c)| {
+                    (c == '#').then_some(Point {
+                        x: x as i64,
+                        y: y as i64,
+                    })
                 })
             })
-        })
-        .collect())
-}
+            .collect())
+    }
 
-pub fn run(init_elves: Input) -> () {
-    let mut sim = Sim::new(init_elves);
-    let p1 = sim.nth(10).unwrap().free_space();
-    println!("{p1}");
-    let p2 = sim.take_while(|x| !x.stopped).count() + 10 + 1;
-    println!("{p2}");
+    fn part1(init_elves: &Input) -> Answer {
+        let mut sim = Sim::new(init_elves.clone()).inspect(ElfMap::emit);
+        sim.nth(10).unwrap().free_space().into()
+    }
+
+    fn part2(init_elves: &Input) -> Answer {
+        let sim = Sim::new(init_elves.clone()).inspect(ElfMap::emit);
+        sim.take_while(|x| !x.stopped).count().into()
+    }
+
+    // a grove `size` squares across, about half full of elves
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let mut grove = String::with_capacity(size * (size + 1));
+        for _ in 0..size.max(1) {
+            grove.extend((0..size.max(1)).map(|_| if rng.gen_bool(0.5) { '#' } else { '.' }));
+            grove.push('\n');
+        }
+        Some(grove)
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -53,8 +84,8 @@ impl Display for Dir {
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
 pub struct Point {
-    x: i64,
-    y: i64,
+    pub x: i64,
+    pub y: i64,
 }
 
 impl Point {
@@ -85,18 +116,21 @@ impl Point {
     }
 }
 
-struct Sim {
+/// The elves spreading out, yielding the map before each round.
+pub struct Sim {
     dirs: [Dir; 4],
     positions: HashSet<Point>,
     stopped: bool,
+    round: usize,
 }
 
 impl Sim {
-    fn new(init: HashSet<Point>) -> Self {
+    pub fn new(init: HashSet<Point>) -> Self {
         Sim {
             dirs: [Dir::N, Dir::S, Dir::W, Dir::E],
             positions: init,
             stopped: false,
+            round: 0,
         }
     }
 }
@@ -153,21 +187,26 @@ impl Iterator for Sim {
         next.extend(proposed.keys());
 
         self.dirs.rotate_left(1);
+        self.round += 1;
 
         Some(ElfMap {
+            round: self.round - 1,
             map: replace(&mut self.positions, next),
             stopped: replace(&mut self.stopped, proposed.is_empty()),
         })
     }
 }
 
-struct ElfMap {
-    map: HashSet<Point>,
-    stopped: bool,
+pub struct ElfMap {
+    // rounds played before this map
+    pub round: usize,
+    pub map: HashSet<Point>,
+    // nobody moved in the round before this
+    pub stopped: bool,
 }
 
 impl ElfMap {
-    fn bounds(&self) -> (Point, Point) {
+    pub fn bounds(&self) -> (Point, Point) {
         match (
             self.map.iter().copied().reduce(|acc, p| Point {
                 x: min(acc.x, p.x),
@@ -184,11 +223,14 @@ impl ElfMap {
         }
     }
 
-    #[allow(dead_code)]
-    fn print(&self) {
+    fn emit(&self) {
+        visualise::emit(|| Frame::from_text(format!("round {}", self.round), &self.render()));
+    }
+
+    fn render(&self) -> String {
         let (min, max) = self.bounds();
 
-        let out: String = (min.y..=max.y)
+        (min.y..=max.y)
             .map(|y| {
                 (min.x..=max.x)
                     .map(|x| {
@@ -200,12 +242,11 @@ impl ElfMap {
                     })
                     .collect::<String>()
             })
-            .join("\n");
-
-        println!("{out}")
+            .join("\n")
     }
 
-    fn free_space(&self) -> usize {
+    /// Empty ground in the smallest rectangle holding every elf.
+    pub fn free_space(&self) -> usize {
         let (min, max) = self.bounds();
         let total = ((max.x.abs_diff(min.x) + 1) * (max.y.abs_diff(min.y) + 1)) as usize;
         total - self.map.len()
@@ -7,66 +7,135 @@ use std::{
 };
 
 use pathfinding::directed::astar;
+use rand::{seq::SliceRandom, Rng, RngCore};
 
-type Input = Sim;
-
-pub fn parse(sim: String) -> Result<Input, get_inputs::Error> {
-    let mut out = Sim {
-        width: sim.lines().next().unwrap().len() - 2,
-        height: sim.lines().count() - 2,
-        blizzards: enum_map! {
-                Dir::N => HashSet::default(),
-                Dir::S => HashSet::default(),
-                Dir::W => HashSet::default(),
-                Dir::E => HashSet::default(),
-        },
-    };
-
-    for (y, line) in sim.lines().skip(1).enumerate() {
-        for (x, c) in line.chars().skip(1).enumerate() {
-            let p = Point { x, y };
-            match c {
-                '^' => {
-                    out.blizzards[Dir::N].insert(p);
-                }
-                'v' => {
-                    out.blizzards[Dir::S].insert(p);
-                }
-                '<' => {
-                    out.blizzards[Dir::W].insert(p);
-                }
-                '>' => {
-                    out.blizzards[Dir::E].insert(p);
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Solution},
+    visualise::{self, Frame},
+};
+
+pub type Input = Sim;
+
+pub struct Day24;
+
+register!(Day24);
+
+impl Solution for Day24 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 24;
+
+    type Input = Input;
+
+    fn parse(sim: String) -> Result<Input, ParseError> {
+        let rows = sim.lines().count();
+        let Some(top) = sim.lines().next().filter(|top| top.len() > 2 && rows > 2) else {
+            return Err(ParseError::at_end(&sim, "a valley surrounded by walls"));
+        };
+
+        let mut out = Sim {
+            width: top.len() - 2,
+            height: rows - 2,
+            blizzards: enum_map! {
+                    Dir::N => HashSet::default(),
+                    Dir::S => HashSet::default(),
+                    Dir::W => HashSet::default(),
+                    Dir::E => HashSet::default(),
+            },
+        };
+
+        for (y, line) in sim.lines().skip(1).enumerate() {
+            for (x, c) in line.chars().skip(1).enumerate() {
+                let p = Point { x, y };
+                match c {
+                    '^' => {
+                        out.blizzards[Dir::N].insert(p);
+                    }
+                    'v' => {
+                        out.blizzards[Dir::S].insert(p);
+                    }
+                    '<' => {
+                        out.blizzards[Dir::W].insert(p);
+                    }
+                    '>' => {
+                        out.blizzards[Dir::E].insert(p);
+                    }
+                    _ => {}
                 }
-                _ => {}
             }
         }
+
+        Ok(out)
     }
 
-    Ok(out)
-}
+    fn part1(sim: &Input) -> Answer {
+        let there = sim.path(0, Node::Start, Node::End).unwrap();
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-enum Node {
-    Start,
-    P(Point),
-    End,
-}
+        there.1.into()
+    }
 
-pub fn run(sim: Input) -> () {
-    let there = sim.path(0, Node::Start, Node::End).unwrap();
+    fn part2(sim: &Input) -> Answer {
+        let there = sim.path(0, Node::Start, Node::End).unwrap();
 
-    println!("{}", there.1);
+        let and_back_again = sim.path(there.1, Node::End, Node::Start).unwrap();
 
-    let and_back_again = sim.path(there.1, Node::End, Node::Start).unwrap();
+        let and_there_again = sim
+            .path(there.1 + and_back_again.1, Node::Start, Node::End)
+            .unwrap();
 
-    let and_there_again = sim
-        .path(there.1 + and_back_again.1, Node::Start, Node::End)
-        .unwrap();
+        for path in [&there.0, &and_back_again.0, &and_there_again.0] {
+            sim.emit_solution(path);
+        }
 
-    println!("{}", there.1 + and_back_again.1 + and_there_again.1);
+        (there.1 + and_back_again.1 + and_there_again.1).into()
+    }
 
-    // sim.print_solution(&solution.0);
+    // a valley `size` squares across and a quarter as tall, up to half full of blizzards;
+    // none blow north or south through the entrance or exit, which would let them out
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let (width, height) = (size.max(3), (size / 4).max(2));
+
+        // fewer blizzards each time the trip turns out impossible, an empty valley never is
+        for attempt in 0.. {
+            let density = 0.5 / (1 + attempt / 8) as f64;
+            let mut valley = format!("#.{}\n", "#".repeat(width));
+            for _ in 0..height {
+                valley.push('#');
+                for x in 0..width {
+                    let blizzards = match x == 0 || x == width - 1 {
+                        true => &['<', '>'][..],
+                        false => &['^', 'v', '<', '>'][..],
+                    };
+                    valley.push(match rng.gen_bool(density) {
+                        true => *blizzards.choose(rng).unwrap(),
+                        false => '.',
+                    });
+                }
+                valley += "#\n";
+            }
+            valley += &format!("{}.#\n", "#".repeat(width));
+
+            let sim = Self::parse(valley.clone()).ok()?;
+            let there = sim.arrival(0, Node::Start, Node::End);
+            let back = there.and_then(|t| sim.arrival(t, Node::End, Node::Start));
+            if back
+                .and_then(|t| sim.arrival(t, Node::Start, Node::End))
+                .is_some()
+            {
+                return Some(valley);
+            }
+        }
+
+        unreachable!()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Node {
+    Start,
+    P(Point),
+    End,
 }
 
 #[derive(Debug, Clone, Copy, Enum, PartialEq, Eq)]
@@ -94,9 +163,9 @@ impl Iterator for Dir {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point {
-    x: usize,
-    y: usize,
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
 }
 
 impl Point {
@@ -119,7 +188,37 @@ pub struct Sim {
 }
 
 impl Sim {
-    fn path(&self, t: usize, from: Node, to: Node) -> Option<(Vec<(usize, Node)>, usize)> {
+    /// The earliest minute `to` can be reached from `from` setting off at minute `t`, if
+    /// ever, which unlike `path` gives up once every square has been tried at every
+    /// point in the blizzards' cycle.
+    pub fn arrival(&self, t: usize, from: Node, to: Node) -> Option<usize> {
+        let cycle = (1..)
+            .map(|n| n * self.width)
+            .find(|n| n % self.height == 0)
+            .unwrap();
+        let mut seen = HashSet::from([(t % cycle, from)]);
+        let mut here = vec![from];
+
+        for t in t.. {
+            if here.contains(&to) {
+                return Some(t);
+            }
+            here = here
+                .into_iter()
+                .flat_map(|p| self.neighbours(p).chain(once(p)))
+                .filter(|&p| self.is_passable_at(p, t + 1) && seen.insert(((t + 1) % cycle, p)))
+                .collect();
+            if here.is_empty() {
+                return None;
+            }
+        }
+
+        unreachable!()
+    }
+
+    /// The quickest way from `from` to `to` setting off at minute `t`, as the
+    /// minute and position of every step along with how long it takes.
+    pub fn path(&self, t: usize, from: Node, to: Node) -> Option<(Vec<(usize, Node)>, usize)> {
         astar::astar::<(usize, Node), usize, _, _, _, _>(
             &(t, from),
             |&(t, p)| {
@@ -192,35 +291,30 @@ impl Sim {
         }
     }
 
-    #[allow(unused)]
-    fn print_solution(&self, path: &Vec<(usize, Node)>) {
+    fn emit_solution(&self, path: &[(usize, Node)]) {
         for &(t, p) in path {
-            println!("Minute {t}");
-            self.print_step(p, t);
-            println!();
+            visualise::emit(|| Frame::from_text(format!("minute {t}"), &self.render_step(p, t)));
         }
     }
 
-    fn print_step(&self, player: Node, t: usize) {
-        let lines = chain!(
+    fn render_step(&self, player: Node, t: usize) -> String {
+        chain!(
             [format!(
                 "#{}{}",
                 if player == Node::Start { 'E' } else { '.' },
                 "#".repeat(self.width)
             )],
-            (0..self.height).map(|y| format!("#{}#", self.print_line(y, player, t))),
+            (0..self.height).map(|y| format!("#{}#", self.render_line(y, player, t))),
             [format!(
                 "{}{}#",
                 "#".repeat(self.width),
                 if player == Node::End { 'E' } else { '.' }
             )],
         )
-        .join("\n");
-
-        println!("{}", lines);
+        .join("\n")
     }
 
-    fn print_line(&self, y: usize, player: Node, t: usize) -> String {
+    fn render_line(&self, y: usize, player: Node, t: usize) -> String {
         (0..self.width)
             .map(|x| {
                 let p = Point { x, y };
@@ -248,7 +342,7 @@ impl Sim {
                     x => return x.to_string().chars().nth(0).unwrap(),
                 }
 
-                return 'X';
+                'X'
             })
             .collect::<String>()
     }
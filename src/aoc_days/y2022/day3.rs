@@ -0,0 +1,121 @@
+use bit_set::BitSet;
+use rand::{seq::SliceRandom, Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Solution},
+};
+
+pub struct Bag {
+    left: BitSet<u64>,
+    right: BitSet<u64>,
+}
+pub type Input = Vec<Bag>;
+
+fn map_from_char(c: char) -> usize {
+    match c {
+        'a'..='z' => ((c as u32) - ('a' as u32) + 1) as usize,
+        'A'..='Z' => ((c as u32) - ('A' as u32) + 26 + 1) as usize,
+        _ => 0,
+    }
+}
+
+pub struct Day3;
+
+register!(Day3);
+
+impl Solution for Day3 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 3;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                    return Err(ParseError::at(
+                        &input,
+                        &line[i..i + c.len_utf8()],
+                        "an item letter",
+                    ));
+                }
+
+                let mut left: BitSet<u64> = BitSet::default();
+                let mut right: BitSet<u64> = BitSet::default();
+
+                let (l, r) = line.split_at(line.len() / 2);
+
+                l.chars().map(map_from_char).for_each(|n| {
+                    left.insert(n);
+                });
+
+                r.chars().map(map_from_char).for_each(|n| {
+                    right.insert(n);
+                });
+
+                Ok(Bag { left, right })
+            })
+            .collect()
+    }
+
+    fn part1(input: &Input) -> Answer {
+        input
+            .iter()
+            .map(|bag| bag.left.intersection(&bag.right).sum::<usize>())
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        input
+            .chunks_exact(3)
+            .map(|group| {
+                let elf0 = group[0].left.union(&group[0].right);
+                let elf1 = group[1].left.union(&group[1].right);
+                let elf2 = group[2].left.union(&group[2].right);
+
+                let mut pool: BitSet<u64> = elf0.collect();
+                pool.intersect_with(&elf1.collect());
+                pool.intersect_with(&elf2.collect());
+
+                pool.iter().sum::<usize>()
+            })
+            .sum::<usize>()
+            .into()
+    }
+
+    // `size` rucksacks, rounded up to whole groups of three
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+        let mut out = String::new();
+
+        for _ in 0..size.div_ceil(3) {
+            let mut items = items.clone();
+            items.shuffle(rng);
+            // every elf gets items of their own, so only the badge is shared by the group
+            let (&badge, items) = items.split_first().unwrap();
+            for own in items.chunks(17) {
+                let (left, right) = own.split_at(own.len() / 2);
+                let common = *own.choose(rng).unwrap();
+                let half = rng.gen_range(2..=16);
+
+                let mut sides = [vec![common], vec![common]];
+                sides[rng.gen_range(0..2)].push(badge);
+                for (side, own) in sides.iter_mut().zip([left, right]) {
+                    while side.len() < half {
+                        side.push(*own.choose(rng).unwrap());
+                    }
+                    side.shuffle(rng);
+                }
+
+                out.extend(sides.iter().flatten());
+                out.push('\n');
+            }
+        }
+
+        Some(out)
+    }
+}
@@ -0,0 +1,94 @@
+use rand::{Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Solution},
+};
+
+pub struct Range {
+    lower: i32,
+    upper: i32,
+}
+
+impl Range {
+    fn new_incl(lower: i32, upper: i32) -> Range {
+        Range {
+            lower,
+            upper: upper + 1,
+        }
+    }
+
+    fn includes(&self, other: &Self) -> bool {
+        // a-x-y-b
+        self.lower <= other.lower && other.upper <= self.upper
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        // ! a--b x--y
+        // ! x--y a--b
+        (other.upper > self.lower) && (self.upper > other.lower)
+    }
+}
+
+pub type Input = Vec<(Range, Range)>;
+
+pub struct Day4;
+
+register!(Day4);
+
+impl Solution for Day4 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 4;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        let mut rangesets: Input = Vec::new();
+
+        for line in input.lines() {
+            let number = |s: &str| {
+                s.parse()
+                    .map_err(|_| ParseError::at(&input, s, "a section number"))
+            };
+
+            let (a, line) = split(&input, line, "-")?;
+            let (b, line) = split(&input, line, ",")?;
+            let (c, d) = split(&input, line, "-")?;
+
+            rangesets.push((
+                Range::new_incl(number(a)?, number(b)?),
+                Range::new_incl(number(c)?, number(d)?),
+            ))
+        }
+
+        Ok(rangesets)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        input
+            .iter()
+            .filter(|(a, b)| a.includes(b) || b.includes(a))
+            .count()
+            .into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        input.iter().filter(|(a, b)| a.overlaps(b)).count().into()
+    }
+
+    // `size` pairs, often overlapping or one containing the other
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let pairs = (0..size).map(|_| {
+            let (a, b) = (rng.gen_range(1..=99), rng.gen_range(1..=99));
+            let (c, d) = (rng.gen_range(a.min(b)..=99), rng.gen_range(1..=99));
+            format!("{}-{},{}-{}\n", a.min(b), a.max(b), c.min(d), c.max(d))
+        });
+        Some(pairs.collect())
+    }
+}
+
+fn split<'a>(input: &str, s: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(sep)
+        .ok_or_else(|| ParseError::at(input, s, format!("a `{sep}`")))
+}
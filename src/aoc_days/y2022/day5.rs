@@ -0,0 +1,183 @@
+use inpt::{inpt, Inpt};
+use rand::{Rng, RngCore};
+use regex::Regex;
+
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Solution},
+};
+
+pub type Input = (Vec<Stack>, Vec<Move>);
+
+pub type Stack = Vec<char>;
+
+fn parse_stacks(input: &str, s: &str) -> Result<Vec<Stack>, ParseError> {
+    let mut stacks: Vec<Stack> = vec![
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    ];
+
+    let stack_re = Regex::new(r"\[.\]").unwrap();
+
+    for line in s.lines() {
+        for package in stack_re.find_iter(line) {
+            let Some(stack) = stacks.get_mut(package.start() / 4) else {
+                return Err(ParseError::at(
+                    input,
+                    &line[package.range()],
+                    "at most nine stacks",
+                ));
+            };
+            stack.push(package.as_str().chars().nth(1).unwrap());
+        }
+    }
+
+    for stack in &mut stacks {
+        stack.reverse();
+    }
+
+    Ok(stacks)
+}
+
+#[derive(Debug, Inpt)]
+#[inpt(regex = r"move (\d+) from (\d+) to (\d+)")]
+pub struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+pub struct Day5;
+
+register!(Day5);
+
+impl Solution for Day5 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 5;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        let (stacks, moves) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::at_end(&input, "a blank line between the stacks and the moves")
+        })?;
+
+        let stacks = parse_stacks(&input, stacks)?;
+        let stack = |n: usize, s: &str| match n {
+            1..=9 => Ok(n - 1),
+            _ => Err(ParseError::at(&input, s, "stack numbers between 1 and 9")),
+        };
+
+        let moves = moves
+            .lines()
+            .filter(|s| !s.is_empty())
+            .map(|s| match inpt(s) {
+                Ok(Move { count, from, to }) => Ok(Move {
+                    count,
+                    from: stack(from, s)?,
+                    to: stack(to, s)?,
+                }),
+                Err(_) => Err(ParseError::at(
+                    &input,
+                    s,
+                    "a move like `move 1 from 2 to 3`",
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok((stacks, moves))
+    }
+
+    fn part1((stacks, moves): &Input) -> Answer {
+        let mut mover9000 = stacks.clone();
+
+        for &Move { count, from, to } in moves {
+            let mut moved = {
+                let stack = mover9000.get_mut(from).unwrap();
+                stack.split_off(stack.len() - count)
+            };
+            moved.reverse();
+
+            mover9000.get_mut(to).unwrap().extend(moved);
+        }
+
+        tops(&mover9000).into()
+    }
+
+    fn part2((stacks, moves): &Input) -> Answer {
+        let mut mover9001 = stacks.clone();
+
+        for &Move { count, from, to } in moves {
+            let moved = {
+                let stack = mover9001.get_mut(from).unwrap();
+                stack.split_off(stack.len() - count)
+            };
+
+            mover9001.get_mut(to).unwrap().extend(moved);
+        }
+
+        tops(&mover9001).into()
+    }
+
+    // nine stacks and `size` moves, none taking more crates than their stack has
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let mut stacks: Vec<Vec<char>> = (0..9)
+            .map(|_| {
+                (0..rng.gen_range(1..=8))
+                    .map(|_| rng.gen_range('A'..='Z'))
+                    .collect()
+            })
+            .collect();
+
+        let mut out = String::new();
+        let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+        for row in (0..height).rev() {
+            let crates: Vec<String> = stacks
+                .iter()
+                .map(|stack| match stack.get(row) {
+                    Some(c) => format!("[{c}]"),
+                    None => "   ".to_owned(),
+                })
+                .collect();
+            out += crates.join(" ").trim_end();
+            out.push('\n');
+        }
+        out += " 1   2   3   4   5   6   7   8   9\n\n";
+
+        for _ in 0..size {
+            let from = loop {
+                let from = rng.gen_range(0..9);
+                if !stacks[from].is_empty() {
+                    break from;
+                }
+            };
+            let to = (from + rng.gen_range(1..9)) % 9;
+            let count = rng.gen_range(1..=stacks[from].len());
+
+            let at = stacks[from].len() - count;
+            let moved = stacks[from].split_off(at);
+            stacks[to].extend(moved);
+            out += &format!("move {} from {} to {}\n", count, from + 1, to + 1);
+        }
+
+        Some(out)
+    }
+}
+
+fn tops(stacks: &[Stack]) -> String {
+    stacks
+        .iter()
+        .map(|stack| match stack.last() {
+            None => "".to_owned(),
+            Some(c) => c.to_string(),
+        })
+        .collect::<String>()
+}
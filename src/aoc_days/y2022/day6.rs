@@ -0,0 +1,63 @@
+use rand::{seq::SliceRandom, Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Solution},
+};
+
+pub type Input = String;
+
+pub struct Day6;
+
+register!(Day6);
+
+impl Solution for Day6 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 6;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        process(input, 4).into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        process(input, 14).into()
+    }
+
+    // a signal of `size` characters with its markers as late as they can be
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let mut letters: Vec<u8> = (b'a'..=b'z').collect();
+        let size = size.max(14);
+        let stretch = (size - 14) / 2;
+
+        // too few letters for either marker, then too few for the message marker
+        let mut signal: Vec<u8> = (0..stretch).map(|_| letters[rng.gen_range(0..3)]).collect();
+        signal.extend((0..stretch).map(|_| letters[rng.gen_range(0..13)]));
+        letters.shuffle(rng);
+        signal.extend(&letters[..14]);
+        signal.extend((signal.len()..size).map(|_| letters[rng.gen_range(0..26)]));
+
+        Some(String::from_utf8(signal).unwrap() + "\n")
+    }
+}
+
+fn process(input: &str, n: usize) -> usize {
+    input
+        .as_bytes()
+        .windows(n)
+        .enumerate()
+        .find_map(|(i, xs)| {
+            let set = xs.iter().collect::<std::collections::HashSet<_>>();
+            if set.len() != n {
+                return None;
+            }
+            Some(i + n)
+        })
+        .unwrap()
+}
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use inpt::{inpt, Inpt};
+use itertools::Itertools;
+use rand::{Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    generate,
+    parse_error::ParseError,
+    solution::{Answer, Solution},
+};
+
+pub type Input = Dir;
+
+#[derive(Debug, Inpt)]
+enum ShellHistory {
+    #[inpt(regex = r"\$ cd (.+)")]
+    CD(Location),
+    #[inpt(regex = r"\$ ls")]
+    LS,
+    #[inpt(regex = r"dir .+")]
+    Directory,
+    #[inpt(regex = r"(\d+) (.+)")]
+    File { size: usize, name: String },
+}
+
+#[derive(Debug, Inpt)]
+enum Location {
+    #[inpt(regex = "/")]
+    Root,
+    #[inpt(regex = "..")]
+    Parent,
+    #[inpt(regex = "(.*)")]
+    Directory(String),
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
+struct QualifiedFile {
+    path: Vec<String>,
+    name: String,
+    size: usize,
+}
+
+pub struct Day7;
+
+register!(Day7);
+
+impl Solution for Day7 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 7;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        let history = input
+            .lines()
+            .map(|x| {
+                inpt::<ShellHistory>(x)
+                    .map_err(|_| ParseError::at(&input, x, "a command or a listing"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut path: Vec<String> = Vec::new();
+
+        let mut files: Vec<QualifiedFile> = history
+            .into_iter()
+            .filter_map(|cmd| match cmd {
+                ShellHistory::CD(loc) => {
+                    match loc {
+                        Location::Root => path.clear(),
+                        Location::Parent => drop(path.pop()),
+                        Location::Directory(name) => path.push(name),
+                    };
+                    None
+                }
+                ShellHistory::LS => None,
+                ShellHistory::Directory => None,
+                ShellHistory::File { size, name } => Some(QualifiedFile {
+                    path: path.clone(),
+                    name,
+                    size,
+                }),
+            })
+            .collect();
+
+        files.sort();
+
+        Ok(treeify(files))
+    }
+
+    fn part1(input: &Input) -> Answer {
+        let sizes = deletable_metrics(input);
+
+        sizes.iter().filter(|&&x| x <= 100000).sum::<usize>().into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        let root_size = input.cached_size;
+        let sizes = deletable_metrics(input);
+
+        let required = root_size - 40000000;
+
+        sizes
+            .iter()
+            .filter(|&&x| x > required)
+            .min()
+            .unwrap()
+            .to_owned()
+            .into()
+    }
+
+    // a session finding about `size` files and directories, which fill most of the disk
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        // no two letter names, which `cd` would take for `..`
+        let mut names = generate::names(rng, size + 1, 5, &[]);
+        let (mut listing, explored, total) = random_dir(rng, &mut names, size);
+
+        // enough that something has to go, but never so much that deleting can't help
+        let filler = rng
+            .gen_range(40_000_001..70_000_000usize)
+            .saturating_sub(total);
+        listing.push(format!("{} {}", filler.max(1), names.pop().unwrap()));
+
+        let history = ["$ cd /".to_owned(), "$ ls".to_owned()]
+            .into_iter()
+            .chain(listing)
+            .chain(explored);
+        Some(history.map(|line| line + "\n").collect())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Dir {
+    cached_size: usize,
+    subdirs: HashMap<String, Dir>,
+    files: HashMap<String, usize>,
+}
+
+fn treeify(qualified_files: Vec<QualifiedFile>) -> Dir {
+    let mut out = Dir::default();
+
+    for QualifiedFile { name, size, .. } in qualified_files.iter().filter(|x| x.path.is_empty()) {
+        out.files.insert(name.to_owned(), size.to_owned());
+        out.cached_size += size;
+    }
+
+    let subdirs = qualified_files
+        .iter()
+        .filter(|x| !x.path.is_empty())
+        .group_by(|x| x.path.first().unwrap());
+
+    for (k, files) in &subdirs {
+        let subdir = treeify(
+            files
+                .map(|x| QualifiedFile {
+                    path: x.path.split_at(1).1.to_vec(),
+                    name: x.name.to_owned(),
+                    size: x.size,
+                })
+                .collect(),
+        );
+        out.cached_size += subdir.cached_size;
+        out.subdirs.insert(k.to_owned(), subdir);
+    }
+
+    out
+}
+
+fn deletable_metrics(dir: &Dir) -> Vec<usize> {
+    let mut out: Vec<usize> = Vec::new();
+
+    out.push(dir.cached_size);
+
+    for subdir in dir.subdirs.values() {
+        out.extend(deletable_metrics(subdir));
+    }
+    out
+}
+
+// the `ls` of a random directory holding `entries` files and directories all told,
+// the commands exploring its subdirectories, and its total size
+fn random_dir(
+    rng: &mut dyn RngCore,
+    names: &mut Vec<String>,
+    entries: usize,
+) -> (Vec<String>, Vec<String>, usize) {
+    let mut listing = Vec::new();
+    let mut explored = Vec::new();
+    let mut total = 0;
+
+    let mut left = entries;
+    while left > 0 {
+        let name = names.pop().unwrap();
+        left -= 1;
+
+        if left > 0 && rng.gen_bool(0.3) {
+            let inside = rng.gen_range(1..=left);
+            left -= inside;
+            let (ls, cds, size) = random_dir(rng, names, inside);
+            listing.push(format!("dir {name}"));
+            explored.extend([format!("$ cd {name}"), "$ ls".to_owned()]);
+            explored.extend(ls.into_iter().chain(cds));
+            explored.push("$ cd ..".to_owned());
+            total += size;
+        } else {
+            let size = rng.gen_range(1000..=300_000);
+            listing.push(format!("{size} {name}"));
+            total += size;
+        }
+    }
+
+    (listing, explored, total)
+}
+
+// let unused = ;
+// let required = root_size - 40000000;
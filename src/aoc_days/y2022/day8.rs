@@ -0,0 +1,117 @@
+use std::cmp;
+
+use grid::Grid;
+use rand::{Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug, Default)]
+pub struct Tree {
+    height: i32,
+    visible: bool,
+    score: i32,
+}
+
+pub type Input = Grid<Tree>;
+
+pub struct Day8;
+
+register!(Day8);
+
+impl Solution for Day8 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 8;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        let mut g = Grid::new(0, 0);
+
+        for line in input.lines() {
+            let row = line
+                .char_indices()
+                .map(|(i, c)| match c.to_digit(10) {
+                    Some(height) => Ok(Tree {
+                        height: height as i32,
+                        visible: false,
+                        score: 1,
+                    }),
+                    None => Err(ParseError::at(
+                        &input,
+                        &line[i..i + c.len_utf8()],
+                        "a tree height",
+                    )),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // the grid can't take an empty row, even as the first
+            if row.is_empty() || (g.cols() != 0 && row.len() != g.cols()) {
+                let expected = match g.cols() {
+                    0 => "a row of trees".to_owned(),
+                    cols => format!("{cols} trees"),
+                };
+                return Err(ParseError::at(&input, line, expected));
+            }
+            g.push_row(row);
+        }
+
+        let (n_rows, n_cols) = g.size();
+
+        for row in 0..n_rows {
+            log::trace!("row {} forward", row);
+            handle_trees(g.iter_row_mut(row));
+            log::trace!("row {} backward", row);
+            handle_trees(g.iter_row_mut(row).rev());
+        }
+
+        for col in 0..n_cols {
+            log::trace!("col {} forward", col);
+            handle_trees(g.iter_col_mut(col));
+            log::trace!("col {} backward", col);
+            handle_trees(g.iter_col_mut(col).rev());
+        }
+
+        Ok(g)
+    }
+
+    fn part1(g: &Input) -> Answer {
+        g.iter().filter(|t| t.visible).count().into()
+    }
+
+    fn part2(g: &Input) -> Answer {
+        g.iter().map(|t| t.score).max().unwrap_or_default().into()
+    }
+
+    // a forest `size` trees square
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let mut forest = String::with_capacity(size * (size + 1));
+        for _ in 0..size.max(1) {
+            forest.extend((0..size.max(1)).map(|_| char::from(b'0' + rng.gen_range(0..10))));
+            forest.push('\n');
+        }
+        Some(forest)
+    }
+}
+
+fn handle_trees<'a, I: Iterator<Item = &'a mut Tree>>(iter: I) {
+    let mut tallest = -1;
+    let mut dist = [0; 10];
+
+    for t in iter {
+        t.visible |= tallest < t.height;
+        t.score *= dist[t.height as usize];
+
+        for (j, d) in dist.iter_mut().enumerate() {
+            if j <= (t.height as usize) {
+                *d = 1;
+            } else {
+                *d += 1;
+            }
+        }
+        tallest = cmp::max(tallest, t.height);
+    }
+}
@@ -0,0 +1,99 @@
+use std::{cmp, collections::HashSet};
+
+use inpt::{inpt, Inpt};
+use rand::{Rng, RngCore};
+
+use crate::{
+    aoc_days::register,
+    parse_error::ParseError,
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug, Inpt)]
+pub enum Direction {
+    #[inpt(regex = "U")]
+    U,
+    #[inpt(regex = "R")]
+    R,
+    #[inpt(regex = "D")]
+    D,
+    #[inpt(regex = "L")]
+    L,
+}
+
+#[derive(Debug, Inpt)]
+#[inpt(regex = r"(.) (\d+)")]
+pub struct Command {
+    direction: Direction,
+    distance: i32,
+}
+
+pub type Input = Vec<Command>;
+
+pub struct Day9;
+
+register!(Day9);
+
+impl Solution for Day9 {
+    const YEAR: i32 = 2022;
+    const DAY: i32 = 9;
+
+    type Input = Input;
+
+    fn parse(input: String) -> Result<Input, ParseError> {
+        input
+            .lines()
+            .map(|s| inpt(s).map_err(|_| ParseError::at(&input, s, "a move like `R 4`")))
+            .collect()
+    }
+
+    fn part1(input: &Input) -> Answer {
+        simulate(input, 2).into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        simulate(input, 10).into()
+    }
+
+    // `size` moves
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let moves = (0..size).map(|_| {
+            let direction = ["U", "R", "D", "L"][rng.gen_range(0..4)];
+            format!("{} {}\n", direction, rng.gen_range(1..=20))
+        });
+        Some(moves.collect())
+    }
+}
+
+type Point = (i32, i32);
+
+fn simulate(input: &Input, knots: usize) -> usize {
+    let mut visited: HashSet<Point> = HashSet::new();
+
+    let mut rope: Vec<Point> = vec![(0, 0); knots];
+    for c in input {
+        for _ in 0..c.distance {
+            match c.direction {
+                Direction::U => rope[0].1 += 1,
+                Direction::R => rope[0].0 += 1,
+                Direction::D => rope[0].1 -= 1,
+                Direction::L => rope[0].0 -= 1,
+            };
+
+            for i in 1..knots {
+                if cmp::max(
+                    rope[i - 1].0.abs_diff(rope[i].0),
+                    rope[i - 1].1.abs_diff(rope[i].1),
+                ) >= 2
+                {
+                    rope[i].0 += (rope[i - 1].0 - rope[i].0).signum();
+                    rope[i].1 += (rope[i - 1].1 - rope[i].1).signum();
+                };
+            }
+
+            visited.insert(rope[knots - 1]);
+        }
+    }
+
+    visited.len()
+}
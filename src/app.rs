@@ -0,0 +1,314 @@
+use std::{io, panic, process::ExitCode};
+
+use crate::{
+    answers::AnswerStore,
+    cli::{self, Args, Command, Format, Visualise},
+    client::{AocClient, Verdict},
+    differential::{self, Summary},
+    generate,
+    leaderboard::{self, Leaderboard},
+    logging, puzzle,
+    runner::{self, Status},
+    scaffold,
+    solution::Part,
+    submit,
+    visualise::{
+        self,
+        export::{GifSink, PngSink},
+        AnsiSink, FrameSink, NullSink, TextSink,
+    },
+};
+
+/// Runs the `aoc` command line with `args`, not including the program name.
+pub fn main(args: impl IntoIterator<Item = String>) -> ExitCode {
+    let command = cli::parse(args).map(|(command, logging)| {
+        logging::init(logging);
+        command
+    });
+
+    match command {
+        Ok(Command::Run(args)) => run(args),
+        Ok(Command::List) => {
+            runner::print_list();
+            ExitCode::SUCCESS
+        }
+        Ok(Command::New { year, day }) => match scaffold::new_day(year, day) {
+            Ok(written) => {
+                for path in written {
+                    println!("created {}", path.display());
+                }
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                println!("{}", err);
+                ExitCode::FAILURE
+            }
+        },
+        Ok(Command::Submit(args)) => submit(args),
+        Ok(Command::FetchPuzzle {
+            year,
+            day,
+            dir,
+            offline,
+        }) => {
+            let client = (!offline).then(AocClient::from_env);
+            match puzzle::fetch_puzzle(client.as_ref(), &dir, "examples".as_ref(), year, day) {
+                Ok(written) => {
+                    for path in written {
+                        println!("wrote {}", path.display());
+                    }
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    println!("{}", err);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Ok(Command::Leaderboard {
+            path,
+            scoring,
+            member,
+        }) => match Leaderboard::load(&path) {
+            Ok(board) => {
+                leaderboard::print_report(&board, scoring, member.as_deref());
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                println!("{}", err);
+                ExitCode::FAILURE
+            }
+        },
+        Ok(Command::Gen {
+            year,
+            day,
+            seed,
+            size,
+        }) => match generate::generate(year, day, seed, size) {
+            Ok(input) => {
+                print!("{}", input);
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                println!("{}", err);
+                ExitCode::FAILURE
+            }
+        },
+        Ok(Command::Diff {
+            year,
+            day,
+            seeds,
+            size,
+        }) => {
+            // the minimiser makes a lot of inputs that panic, which are reported anyway
+            let hook = panic::take_hook();
+            panic::set_hook(Box::new(|_| {}));
+            let summary = differential::differential(year, day, 0..seeds, size);
+            panic::set_hook(hook);
+
+            match summary {
+                Ok(Summary {
+                    compared: 0,
+                    mismatch: None,
+                }) => {
+                    println!(
+                        "{year} day {day} compared nothing, none of the {seeds} inputs were small \
+                         enough for its reference, try a smaller --size"
+                    );
+                    ExitCode::FAILURE
+                }
+                Ok(Summary {
+                    compared,
+                    mismatch: None,
+                }) => {
+                    println!("{year} day {day} agrees with its reference on {compared} inputs");
+                    ExitCode::SUCCESS
+                }
+                Ok(Summary {
+                    mismatch: Some(mismatch),
+                    ..
+                }) => {
+                    println!(
+                        "{year} day {day}, seed {}: {}\nsmallest input it still happens on:\n{}",
+                        mismatch.seed, mismatch.failure, mismatch.input
+                    );
+                    ExitCode::FAILURE
+                }
+                Err(err) => {
+                    println!("{}", err);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Err(err) => {
+            println!("{}\n{}", err, cli::USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> ExitCode {
+    let source = args.input_source();
+
+    // frames go to stderr, out of the way of the table
+    let sink: Option<Box<dyn FrameSink>> = match &args.visualise {
+        Some(Visualise::Text) => Some(Box::new(TextSink(io::stderr()))),
+        Some(Visualise::Ansi { fps }) => Some(Box::new(AnsiSink::new(io::stderr(), *fps))),
+        Some(Visualise::Png { dir, palette, cell }) => {
+            Some(Box::new(PngSink::new(dir, palette.clone(), *cell)))
+        }
+        Some(Visualise::Gif {
+            path,
+            palette,
+            cell,
+            fps,
+        }) => Some(Box::new(GifSink::new(path, palette.clone(), *cell, *fps))),
+        Some(Visualise::Null) => Some(Box::new(NullSink)),
+        None => None,
+    };
+    if let Some(sink) = sink {
+        visualise::install_every(sink, args.every);
+    }
+
+    let reports = runner::run_days(
+        source.as_ref(),
+        args.year,
+        &args.days,
+        args.repeat,
+        args.part,
+        args.jobs,
+    );
+
+    if let Err(err) = visualise::finish() {
+        eprintln!("visualisation failed: {}", err);
+    }
+
+    match args.format {
+        Format::Text => {
+            runner::print_table(&reports);
+
+            for r in &reports {
+                match &r.status {
+                    Status::ParseError(err) => println!("\n{}", err),
+                    Status::InputError(msg) | Status::Panicked(msg) => {
+                        println!("\n{} day {} {}: {}", r.year, r.day, r.status, msg)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Format::Json => runner::print_json(&reports),
+    }
+
+    // keep stdout machine readable when it is json
+    let note = |msg: String| match args.format {
+        Format::Text => println!("{}", msg),
+        Format::Json => eprintln!("{}", msg),
+    };
+
+    // a day that went wrong fails the run, so a whole year can be checked by exit code
+    let outcome = match reports.iter().any(|r| r.status.is_failure()) {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    };
+
+    if !(args.check || args.record) {
+        return outcome;
+    }
+
+    let mut store = match AnswerStore::load(&args.answers) {
+        Ok(store) => store,
+        Err(err) => {
+            note(format!("{}: {}", args.answers.display(), err));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if args.check {
+        let mismatches = store.check(&reports, args.part);
+        if !mismatches.is_empty() {
+            note(format!(
+                "\n{} answer(s) differ from {}:\n",
+                mismatches.len(),
+                store.path().display()
+            ));
+            for mismatch in mismatches {
+                note(mismatch.to_string());
+            }
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if args.record {
+        store.record(&reports);
+        if let Err(err) = store.save() {
+            note(format!("{}: {}", store.path().display(), err));
+            return ExitCode::FAILURE;
+        }
+    }
+
+    outcome
+}
+
+fn submit(args: Args) -> ExitCode {
+    let source = args.input_source();
+    let (day, part) = (args.days[0], args.part.unwrap_or(Part::One));
+
+    let report = runner::run_day(source.as_ref(), args.year, day, 1, Some(part));
+    match &report.status {
+        Status::Ok => {}
+        Status::ParseError(err) => {
+            println!("{}", err);
+            return ExitCode::FAILURE;
+        }
+        Status::InputError(msg) | Status::Panicked(msg) => {
+            println!("{} day {} {}: {}", args.year, day, report.status, msg);
+            return ExitCode::FAILURE;
+        }
+        Status::NotImplemented => {
+            println!("{} day {} {}", args.year, day, report.status);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let mut store = match AnswerStore::load(&args.answers) {
+        Ok(store) => store,
+        Err(err) => {
+            println!("{}: {}", args.answers.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let answer = match part {
+        Part::One => &report.part1,
+        Part::Two => &report.part2,
+    };
+
+    match submit::submit(
+        &AocClient::from_env(),
+        &mut store,
+        args.year,
+        day,
+        part,
+        answer,
+    ) {
+        Ok(verdict) => {
+            println!(
+                "{} day {} part {}: {}, {}",
+                args.year,
+                day,
+                part.number(),
+                answer,
+                verdict
+            );
+            match verdict {
+                Verdict::Right | Verdict::AlreadySolved => ExitCode::SUCCESS,
+                Verdict::Wrong(_) | Verdict::Wait { .. } => ExitCode::FAILURE,
+            }
+        }
+        Err(err) => {
+            println!("{} day {} part {}: {}", args.year, day, part.number(), err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,601 @@
+use std::{num::NonZeroUsize, path::PathBuf, thread};
+
+use crate::{
+    aoc_days,
+    client::AocClient,
+    inputs::{CacheDir, Cached, Examples, Fetcher, InputFile, InputSource, Stdin},
+    leaderboard::Scoring,
+    logging::Logging,
+    runner,
+    solution::Part,
+    visualise::export::Palette,
+};
+
+pub const USAGE: &str = "usage: aoc list
+       aoc new <day> [--year YYYY]
+       aoc submit <day> <1|2> [run options]
+       aoc fetch-puzzle <day> [--year YYYY] [--puzzle-dir DIR] [--offline]
+       aoc leaderboard <file.json> [--scoring local|stars|delta] [--member NAME]
+       aoc gen <day> [--year YYYY] [--seed N] [--size N]
+       aoc diff <day> [--year YYYY] [--seeds N] [--size N]
+       aoc <all | day[-day][,...]> [--year YYYY] [--repeat N] [--jobs N] [--part 1|2]
+           [--input example|real|PATH] [--input-dir DIR] [--offline] [--stdin]
+           [--check] [--record] [--answers FILE] [--format text|json]
+           [--visualise text|ansi|png|gif|null] [--fps N] [--out PATH] [--cell N]
+           [--palette C=RRGGBB,...] [--every N]
+any command also takes -v or -vv for diagnostics, and --log-days SELECTION to only
+show those days' own";
+
+#[derive(Debug)]
+pub enum Command {
+    Run(Args),
+    // which days and parts have a solution
+    List,
+    // scaffold a day from the template
+    New {
+        year: i32,
+        day: i32,
+    },
+    // run one part and post its answer, `args.part` is always set
+    Submit(Args),
+    // save the statement and fill in the example fixture from it
+    FetchPuzzle {
+        year: i32,
+        day: i32,
+        dir: PathBuf,
+        offline: bool,
+    },
+    // report on a private leaderboard's json export
+    Leaderboard {
+        path: PathBuf,
+        scoring: Scoring,
+        member: Option<String>,
+    },
+    // print a random input for stress testing
+    Gen {
+        year: i32,
+        day: i32,
+        seed: u64,
+        size: usize,
+    },
+    // compare a day's solution to its reference on generated inputs
+    Diff {
+        year: i32,
+        day: i32,
+        // how many inputs, from seed 0 on
+        seeds: u64,
+        size: usize,
+    },
+}
+
+#[derive(Debug)]
+pub struct Args {
+    pub year: i32,
+    pub days: Vec<i32>,
+    pub repeat: usize,
+    // how many days run at once
+    pub jobs: usize,
+    // both parts when not given
+    pub part: Option<Part>,
+    pub input: InputChoice,
+    pub check: bool,
+    pub record: bool,
+    pub answers: PathBuf,
+    pub format: Format,
+    // where simulations draw their frames, if anywhere
+    pub visualise: Option<Visualise>,
+    // only every nth frame is drawn
+    pub every: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Visualise {
+    Text,
+    Ansi {
+        fps: u32,
+    },
+    // a numbered image per frame in `dir`
+    Png {
+        dir: PathBuf,
+        palette: Palette,
+        cell: usize,
+    },
+    Gif {
+        path: PathBuf,
+        palette: Palette,
+        cell: usize,
+        fps: u32,
+    },
+    Null,
+}
+
+#[derive(Debug)]
+pub enum InputChoice {
+    // the cache directory, downloading anything missing unless offline
+    Cached { dir: PathBuf, offline: bool },
+    Examples(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+
+impl Args {
+    pub fn input_source(&self) -> Box<dyn InputSource> {
+        match &self.input {
+            InputChoice::Cached { dir, offline: true } => Box::new(CacheDir::new(dir, self.year)),
+            InputChoice::Cached {
+                dir,
+                offline: false,
+            } => Box::new(Cached {
+                cache: CacheDir::new(dir, self.year),
+                fallback: Fetcher {
+                    client: AocClient::from_env(),
+                    year: self.year,
+                },
+            }),
+            InputChoice::Examples(root) => Box::new(Examples {
+                root: root.clone(),
+                year: self.year,
+            }),
+            InputChoice::File(path) => Box::new(InputFile(path.clone())),
+            InputChoice::Stdin => Box::new(Stdin),
+        }
+    }
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<(Command, Logging), String> {
+    let (logging, args) = parse_logging(args)?;
+    let mut args = args.into_iter().peekable();
+
+    let command = match args.peek().map(String::as_str) {
+        Some("list") => match args.nth(1) {
+            None => Ok(Command::List),
+            Some(extra) => Err(format!("unexpected argument `{extra}`")),
+        },
+        Some("new") => parse_new(args.skip(1)),
+        Some("submit") => parse_submit(args.skip(1)),
+        Some("fetch-puzzle") => parse_fetch_puzzle(args.skip(1)),
+        Some("leaderboard") => parse_leaderboard(args.skip(1)),
+        Some("gen") => parse_gen(args.skip(1)),
+        Some("diff") => parse_diff(args.skip(1)),
+        _ => parse_run(args).map(Command::Run),
+    }?;
+
+    Ok((command, logging))
+}
+
+// pulls out the logging flags, which go with any command
+fn parse_logging(args: impl IntoIterator<Item = String>) -> Result<(Logging, Vec<String>), String> {
+    let mut verbosity = 0;
+    let mut days = None;
+    let mut rest = Vec::new();
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-v" | "--verbose" => verbosity += 1,
+            "-vv" => verbosity += 2,
+            "--log-days" => {
+                let selection = args.next().ok_or("--log-days needs a value")?;
+                days = Some(runner::parse_days(&selection)?);
+            }
+            _ => rest.push(arg),
+        }
+    }
+
+    let logging = Logging {
+        level: Logging::verbosity(verbosity),
+        days,
+    };
+    Ok((logging, rest))
+}
+
+// any year there has been a puzzle, whether or not it has solutions here yet
+fn parse_year(year: &str) -> Result<i32, String> {
+    year.parse()
+        .ok()
+        .filter(|&year| year >= 2015)
+        .ok_or_else(|| "--year needs a year from 2015 on".to_owned())
+}
+
+// the one day that `command` works on
+fn parse_day(command: &str, selection: &str) -> Result<i32, String> {
+    match runner::parse_days(selection)?[..] {
+        [day] => Ok(day),
+        _ => Err(format!("{command} takes a single day")),
+    }
+}
+
+fn parse_new(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut year = aoc_days::latest();
+    let mut day = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(&args.next().ok_or("--year needs a value")?)?,
+            flag if flag.starts_with("--") => return Err(format!("unknown flag {flag}")),
+            d if day.is_none() => day = Some(parse_day("new", d)?),
+            extra => return Err(format!("unexpected argument `{extra}`")),
+        }
+    }
+
+    Ok(Command::New {
+        year,
+        day: day.ok_or("no day given")?,
+    })
+}
+
+fn parse_submit(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    let day = args.next().ok_or("submit needs a day")?;
+    let part = args.next().ok_or("submit needs a part")?;
+
+    let args = parse_run([day, "--part".to_owned(), part].into_iter().chain(args))?;
+
+    if args.days.len() != 1 {
+        return Err("submit takes a single day".into());
+    }
+    if matches!(args.input, InputChoice::Examples(_)) {
+        return Err("submit needs the real input".into());
+    }
+
+    Ok(Command::Submit(args))
+}
+
+fn parse_fetch_puzzle(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut year = aoc_days::latest();
+    let mut day = None;
+    let mut dir = PathBuf::from("puzzles");
+    let mut offline = false;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(&args.next().ok_or("--year needs a value")?)?,
+            "--puzzle-dir" => dir = args.next().ok_or("--puzzle-dir needs a value")?.into(),
+            "--offline" => offline = true,
+            flag if flag.starts_with("--") => return Err(format!("unknown flag {flag}")),
+            d if day.is_none() => day = Some(parse_day("fetch-puzzle", d)?),
+            extra => return Err(format!("unexpected argument `{extra}`")),
+        }
+    }
+
+    Ok(Command::FetchPuzzle {
+        year,
+        day: day.ok_or("no day given")?,
+        dir,
+        offline,
+    })
+}
+
+fn parse_gen(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut year = aoc_days::latest();
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = 100;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(&args.next().ok_or("--year needs a value")?)?,
+            "--seed" => {
+                seed = args
+                    .next()
+                    .and_then(|seed| seed.parse().ok())
+                    .ok_or("--seed needs a number")?
+            }
+            "--size" => {
+                size = args
+                    .next()
+                    .and_then(|size| size.parse().ok())
+                    .filter(|&size| size > 0)
+                    .ok_or("--size needs a positive number")?
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag {flag}")),
+            d if day.is_none() => day = Some(parse_day("gen", d)?),
+            extra => return Err(format!("unexpected argument `{extra}`")),
+        }
+    }
+
+    Ok(Command::Gen {
+        year,
+        day: day.ok_or("no day given")?,
+        seed,
+        size,
+    })
+}
+
+fn parse_diff(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut year = aoc_days::latest();
+    let mut day = None;
+    let mut seeds = 100;
+    // the references are slow, so small inputs unless asked for more
+    let mut size = 8;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(&args.next().ok_or("--year needs a value")?)?,
+            "--seeds" => {
+                seeds = args
+                    .next()
+                    .and_then(|seeds| seeds.parse().ok())
+                    .filter(|&seeds| seeds > 0)
+                    .ok_or("--seeds needs a positive number")?
+            }
+            "--size" => {
+                size = args
+                    .next()
+                    .and_then(|size| size.parse().ok())
+                    .filter(|&size| size > 0)
+                    .ok_or("--size needs a positive number")?
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag {flag}")),
+            d if day.is_none() => day = Some(parse_day("diff", d)?),
+            extra => return Err(format!("unexpected argument `{extra}`")),
+        }
+    }
+
+    Ok(Command::Diff {
+        year,
+        day: day.ok_or("no day given")?,
+        seeds,
+        size,
+    })
+}
+
+fn parse_leaderboard(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut path = None;
+    let mut scoring = Scoring::Local;
+    let mut member = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--scoring" => {
+                scoring = match args.next().as_deref() {
+                    Some("local") => Scoring::Local,
+                    Some("stars") => Scoring::Stars,
+                    Some("delta") => Scoring::Delta,
+                    _ => return Err("--scoring needs local, stars or delta".into()),
+                }
+            }
+            "--member" => member = Some(args.next().ok_or("--member needs a name")?),
+            flag if flag.starts_with("--") => return Err(format!("unknown flag {flag}")),
+            file if path.is_none() => path = Some(file.into()),
+            extra => return Err(format!("unexpected argument `{extra}`")),
+        }
+    }
+
+    Ok(Command::Leaderboard {
+        path: path.ok_or("no leaderboard file given")?,
+        scoring,
+        member,
+    })
+}
+
+fn parse_run(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut year = aoc_days::latest();
+    let mut days = None;
+    let mut repeat = 1;
+    let mut jobs = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let mut part = None;
+    let mut example = false;
+    let mut dir = PathBuf::from("inputs");
+    let mut offline = false;
+    let mut single = None;
+    let mut check = false;
+    let mut record = false;
+    let mut answers = None;
+    let mut format = Format::Text;
+    let mut visualise = None;
+    let mut fps = 10;
+    let mut out = None;
+    let mut cell = 8;
+    let mut palette = Palette::default();
+    let mut every = 1;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| args.next().ok_or_else(|| format!("{flag} needs a value"));
+
+        match arg.as_str() {
+            "--year" => year = parse_year(&value("--year")?)?,
+            "--repeat" => {
+                repeat = value("--repeat")?
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or("--repeat needs a positive number")?
+            }
+            "--jobs" => {
+                jobs = value("--jobs")?
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or("--jobs needs a positive number")?
+            }
+            "--part" => {
+                part = match value("--part")?.as_str() {
+                    "1" => Some(Part::One),
+                    "2" => Some(Part::Two),
+                    other => return Err(format!("`{other}` is not a part, expected 1 or 2")),
+                }
+            }
+            "--input" => match value("--input")?.as_str() {
+                "example" => (example, single) = (true, None),
+                "real" => (example, single) = (false, None),
+                path => (example, single) = (false, Some(InputChoice::File(path.into()))),
+            },
+            "--input-dir" => dir = value("--input-dir")?.into(),
+            "--offline" => offline = true,
+            "--stdin" => single = Some(InputChoice::Stdin),
+            "--check" => check = true,
+            "--record" => record = true,
+            "--answers" => answers = Some(value("--answers")?.into()),
+            "--format" => {
+                format = match value("--format")?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    other => return Err(format!("unknown format `{other}`")),
+                }
+            }
+            "--visualise" => visualise = Some(value("--visualise")?),
+            "--fps" => {
+                fps = value("--fps")?
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or("--fps needs a positive number")?
+            }
+            "--out" => out = Some(PathBuf::from(value("--out")?)),
+            "--cell" => {
+                cell = value("--cell")?
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or("--cell needs a positive number of pixels")?
+            }
+            "--palette" => palette = Palette::parse(&value("--palette")?)?,
+            "--every" => {
+                every = value("--every")?
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or("--every needs a positive number of frames")?
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag {flag}")),
+            selection if days.is_none() => days = Some(runner::parse_days(selection)?),
+            extra => return Err(format!("unexpected argument `{extra}`")),
+        }
+    }
+
+    let days = days.ok_or("no days selected")?;
+
+    let input = match single {
+        Some(_) if days.len() != 1 => {
+            return Err("--input PATH and --stdin need a single day".into())
+        }
+        Some(single) => single,
+        None if example => InputChoice::Examples("examples".into()),
+        None => InputChoice::Cached { dir, offline },
+    };
+
+    // the answer store only holds answers for the real inputs
+    if (check || record) && !matches!(input, InputChoice::Cached { .. }) {
+        return Err("--check and --record need the real input".into());
+    }
+
+    let visualise = match visualise.as_deref() {
+        None => None,
+        Some("text") => Some(Visualise::Text),
+        Some("ansi") => Some(Visualise::Ansi { fps }),
+        Some("png") => Some(Visualise::Png {
+            dir: out.unwrap_or_else(|| "frames".into()),
+            palette,
+            cell,
+        }),
+        Some("gif") => Some(Visualise::Gif {
+            path: out.unwrap_or_else(|| "frames.gif".into()),
+            palette,
+            cell,
+            fps,
+        }),
+        Some("null") => Some(Visualise::Null),
+        Some(other) => return Err(format!("unknown visualisation `{other}`")),
+    };
+
+    Ok(Args {
+        year,
+        days,
+        repeat,
+        // frames from days running at once would interleave
+        jobs: if visualise.is_some() { 1 } else { jobs },
+        part,
+        input,
+        check,
+        record,
+        answers: answers.unwrap_or_else(|| format!("answers/{year}.txt").into()),
+        format,
+        visualise,
+        every,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_run;
+    use crate::{
+        inputs::Examples,
+        runner::{self, Status},
+    };
+
+    fn run(args: &str) -> Result<super::Args, String> {
+        parse_run(args.split_whitespace().map(str::to_owned))
+    }
+
+    #[test]
+    fn years_without_solutions_run_as_not_implemented() {
+        let args = run("1 --year 2016 --input example").unwrap();
+        let source = Examples {
+            root: concat!(env!("CARGO_MANIFEST_DIR"), "/examples").into(),
+            year: args.year,
+        };
+
+        let report = runner::run_day(&source, args.year, 1, 1, None);
+
+        assert!(matches!(report.status, Status::NotImplemented));
+    }
+
+    #[test]
+    fn years_before_the_first_puzzles_are_an_error() {
+        assert_eq!(
+            run("1 --year 2014").unwrap_err(),
+            "--year needs a year from 2015 on"
+        );
+    }
+
+    #[test]
+    fn answers_are_only_checked_and_recorded_for_the_real_input() {
+        for flag in ["--check", "--record"] {
+            assert!(run(&format!("1 {flag}")).is_ok());
+            assert!(run(&format!("1 {flag} --input real")).is_ok());
+        }
+    }
+
+    #[test]
+    fn answers_for_the_example_are_not_checked_or_recorded() {
+        for flag in ["--check", "--record"] {
+            assert_eq!(
+                run(&format!("1 {flag} --input example")).unwrap_err(),
+                "--check and --record need the real input"
+            );
+        }
+    }
+
+    #[test]
+    fn answers_for_another_file_are_not_checked_or_recorded() {
+        for flag in ["--check", "--record"] {
+            assert_eq!(
+                run(&format!("1 {flag} --input other.txt")).unwrap_err(),
+                "--check and --record need the real input"
+            );
+        }
+    }
+
+    #[test]
+    fn answers_from_stdin_are_not_checked_or_recorded() {
+        for flag in ["--check", "--record"] {
+            assert_eq!(
+                run(&format!("1 {flag} --stdin")).unwrap_err(),
+                "--check and --record need the real input"
+            );
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::fmt::Display;
+
+use crate::solution::Part;
+
+/// Talks to the Advent of Code website, or anything serving the same paths.
+#[derive(Debug, Clone)]
+pub struct AocClient {
+    base_url: String,
+    session: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    // 404, the puzzle has not been released yet
+    NotUnlocked,
+    // 400, the session cookie is missing or has expired
+    BadSession,
+    RateLimited { retry_after: Option<u64> },
+    Status(u16),
+    Transport(String),
+    // a page we don't know how to read, e.g. after the site changed
+    Unrecognised(String),
+}
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong(Option<Hint>),
+    // submitted too soon after the last wrong answer
+    Wait { secs: u64 },
+    AlreadySolved,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Right => f.write_str("that's the right answer"),
+            Verdict::Wrong(None) => f.write_str("that's not the right answer"),
+            Verdict::Wrong(Some(Hint::TooHigh)) => f.write_str("that's too high"),
+            Verdict::Wrong(Some(Hint::TooLow)) => f.write_str("that's too low"),
+            Verdict::Wait { secs } => write!(f, "answered too recently, wait {}s", secs),
+            Verdict::AlreadySolved => f.write_str("that part is already solved"),
+        }
+    }
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::NotUnlocked => f.write_str("that puzzle is not unlocked yet"),
+            ClientError::BadSession => {
+                f.write_str("the session cookie is missing or expired, set AOC_SESSION")
+            }
+            ClientError::RateLimited {
+                retry_after: Some(secs),
+            } => write!(f, "rate limited, try again in {}s", secs),
+            ClientError::RateLimited { retry_after: None } => f.write_str("rate limited"),
+            ClientError::Status(code) => write!(f, "unexpected response {}", code),
+            ClientError::Transport(err) => f.write_str(err),
+            ClientError::Unrecognised(page) => write!(f, "unrecognised response: {}", page),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl AocClient {
+    pub const DEFAULT_URL: &'static str = "https://adventofcode.com";
+
+    pub fn new(base_url: impl Into<String>, session: Option<String>) -> Self {
+        AocClient {
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            session,
+        }
+    }
+
+    /// Configured from `AOC_URL`, defaulting to the real site, and `AOC_SESSION`.
+    pub fn from_env() -> Self {
+        AocClient::new(
+            std::env::var("AOC_URL").unwrap_or_else(|_| Self::DEFAULT_URL.to_owned()),
+            std::env::var("AOC_SESSION").ok(),
+        )
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn input(&self, year: i32, day: i32) -> Result<String, ClientError> {
+        read(
+            self.request("GET", &format!("/{year}/day/{day}/input"))
+                .call(),
+        )
+    }
+
+    /// The puzzle page, which only shows part two once part one is solved.
+    pub fn puzzle(&self, year: i32, day: i32) -> Result<String, ClientError> {
+        read(self.request("GET", &format!("/{year}/day/{day}")).call())
+    }
+
+    pub fn submit(
+        &self,
+        year: i32,
+        day: i32,
+        part: Part,
+        answer: &str,
+    ) -> Result<Verdict, ClientError> {
+        let page = read(
+            self.request("POST", &format!("/{year}/day/{day}/answer"))
+                .send_form(&[("level", &part.number().to_string()), ("answer", answer)]),
+        )?;
+
+        verdict(&page).ok_or_else(|| ClientError::Unrecognised(article(&page).to_owned()))
+    }
+
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        let request = ureq::request(method, &format!("{}{}", self.base_url, path)).set(
+            "User-Agent",
+            concat!("aoc/", env!("CARGO_PKG_VERSION"), " (self-hosted runner)"),
+        );
+
+        match &self.session {
+            Some(session) => request.set("Cookie", &format!("session={session}")),
+            None => request,
+        }
+    }
+}
+
+fn read(response: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|err| ClientError::Transport(err.to_string())),
+        Err(ureq::Error::Status(400, _)) => Err(ClientError::BadSession),
+        Err(ureq::Error::Status(404, _)) => Err(ClientError::NotUnlocked),
+        Err(ureq::Error::Status(429 | 503, response)) => Err(ClientError::RateLimited {
+            retry_after: response
+                .header("Retry-After")
+                .and_then(|secs| secs.trim().parse().ok()),
+        }),
+        Err(ureq::Error::Status(code, _)) => Err(ClientError::Status(code)),
+        Err(err) => Err(ClientError::Transport(err.to_string())),
+    }
+}
+
+fn verdict(page: &str) -> Option<Verdict> {
+    let text = article(page);
+
+    if text.contains("That's the right answer") {
+        Some(Verdict::Right)
+    } else if text.contains("That's not the right answer") {
+        Some(Verdict::Wrong(if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        }))
+    } else if text.contains("You gave an answer too recently") {
+        // "You have 1m 4s left to wait."
+        let left = text.split("You have ").nth(1)?.split(" left").next()?;
+        let secs = left.split_whitespace().try_fold(0, |secs, unit| {
+            match unit.split_at(unit.len().checked_sub(1)?) {
+                (n, "m") => Some(secs + n.parse::<u64>().ok()? * 60),
+                (n, "s") => Some(secs + n.parse::<u64>().ok()?),
+                _ => None,
+            }
+        })?;
+        Some(Verdict::Wait { secs })
+    } else if text.contains("You don't seem to be solving the right level") {
+        Some(Verdict::AlreadySolved)
+    } else {
+        None
+    }
+}
+
+// the message is the page's only <article>
+fn article(page: &str) -> &str {
+    page.split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article)
+        .trim()
+}
@@ -0,0 +1,263 @@
+//! Differential testing of the clever solutions against slow reference ones.
+//!
+//! Days with a [`Solution::reference`](crate::solution::Solution::reference) are run on
+//! generated inputs until the two disagree, and the input they disagree on is then cut
+//! down as far as it will go while they still do.
+
+use std::{
+    fmt::Display,
+    ops::Range,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::{
+    aoc_days::{self, Registration},
+    generate::{self, GenerateError},
+    parse_error::ParseError,
+    runner::panic_message,
+    solution::Comparison,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffError {
+    Generate(GenerateError),
+    NoReference { year: i32, day: i32 },
+}
+
+impl Display for DiffError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiffError::Generate(err) => err.fmt(f),
+            DiffError::NoReference { year, day } => {
+                write!(
+                    f,
+                    "{year} day {day} has no reference solver to compare against"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for DiffError {}
+
+impl From<GenerateError> for DiffError {
+    fn from(err: GenerateError) -> Self {
+        DiffError::Generate(err)
+    }
+}
+
+/// How an input went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Disagreed(Comparison),
+    Unparsable(String),
+    Panicked(String),
+}
+
+impl Failure {
+    // whether `other` is the same problem, if not with the same numbers
+    fn alike(&self, other: &Failure) -> bool {
+        match (self, other) {
+            (Failure::Disagreed(a), Failure::Disagreed(b)) => a.what == b.what,
+            (Failure::Unparsable(a), Failure::Unparsable(b))
+            | (Failure::Panicked(a), Failure::Panicked(b)) => gist(a) == gist(b),
+            _ => false,
+        }
+    }
+}
+
+// the first line of a message with each number made 0, as lengths, indices and
+// positions change with the input while what went wrong stays the same
+fn gist(message: &str) -> String {
+    let mut gist = String::new();
+    let mut in_number = false;
+    for c in message.lines().next().unwrap_or_default().chars() {
+        match (c.is_ascii_digit(), in_number) {
+            (true, true) => {}
+            (true, false) => gist.push('0'),
+            (false, _) => gist.push(c),
+        }
+        in_number = c.is_ascii_digit();
+    }
+    gist
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Disagreed(Comparison {
+                what,
+                reference,
+                solution,
+            }) => write!(
+                f,
+                "{what}: the reference says {reference}, the solution says {solution}"
+            ),
+            Failure::Unparsable(err) => write!(f, "the input doesn't parse: {err}"),
+            Failure::Panicked(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+/// The first generated input the reference and the solution disagreed on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub seed: u64,
+    // as small as it would go, with `failure` as it is for this input
+    pub input: String,
+    pub failure: Failure,
+}
+
+/// What came of checking a day against its reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    // inputs the reference could take on, rather than all of those generated
+    pub compared: u64,
+    pub mismatch: Option<Mismatch>,
+}
+
+/// Checks the solution for `day` against its reference on an input of `size` from each
+/// of `seeds`, stopping at the first mismatch. Panics are caught and reported as
+/// failures, but the panic hook is left alone, so quieting it is up to the caller.
+pub fn differential(
+    year: i32,
+    day: i32,
+    seeds: Range<u64>,
+    size: usize,
+) -> Result<Summary, DiffError> {
+    let registration = aoc_days::find(year, day).ok_or(GenerateError::NoSolution { year, day })?;
+
+    let mut compared = 0;
+    for seed in seeds {
+        let input = (registration.generate)(&mut generate::rng(seed), size)
+            .ok_or(GenerateError::NoGenerator { year, day })?;
+        match check(registration, &input) {
+            None => return Err(DiffError::NoReference { year, day }),
+            // too big for the reference
+            Some(Ok(0)) => {}
+            Some(Ok(_)) => compared += 1,
+            Some(Err(failure)) => {
+                let (input, failure) = minimise(input, failure, |input| {
+                    check(registration, input).and_then(Result::err)
+                });
+                return Ok(Summary {
+                    compared: compared + 1,
+                    mismatch: Some(Mismatch {
+                        seed,
+                        input,
+                        failure,
+                    }),
+                });
+            }
+        }
+    }
+
+    Ok(Summary {
+        compared,
+        mismatch: None,
+    })
+}
+
+// how many answers were compared, or `None` without a reference to compare against
+fn check(registration: &Registration, input: &str) -> Option<Result<usize, Failure>> {
+    let compared = panic::catch_unwind(AssertUnwindSafe(|| {
+        let parsed = (registration.parse)(input.to_owned())?;
+        Ok::<_, ParseError>((registration.reference)(&*parsed))
+    }));
+
+    match compared {
+        Ok(Ok(comparisons)) => {
+            comparisons.map(
+                |comparisons| match comparisons.iter().find(|c| !c.agrees()) {
+                    Some(comparison) => Err(Failure::Disagreed(comparison.clone())),
+                    None => Ok(comparisons.len()),
+                },
+            )
+        }
+        Ok(Err(err)) => Some(Err(Failure::Unparsable(err.to_string()))),
+        Err(payload) => Some(Err(Failure::Panicked(panic_message(payload)))),
+    }
+}
+
+/// Cuts `input` down while `fails` keeps giving a failure like `failure`: first whole
+/// blank-line separated blocks, then lines, then makes each number smaller.
+pub fn minimise(
+    input: String,
+    failure: Failure,
+    fails: impl Fn(&str) -> Option<Failure>,
+) -> (String, Failure) {
+    let mut smallest = (input, failure);
+    let attempt = |candidate: String, smallest: &mut (String, Failure)| match fails(&candidate)
+        .filter(|f| f.alike(&smallest.1))
+    {
+        Some(failure) => {
+            *smallest = (candidate, failure);
+            true
+        }
+        None => false,
+    };
+
+    // every change that sticks makes the input shorter or one of its numbers smaller,
+    // so this runs out eventually
+    loop {
+        let start = smallest.0.clone();
+        for separator in ["\n\n", "\n"] {
+            loop {
+                let current = smallest.0.clone();
+                if !without_some(&current, separator).any(|c| attempt(c, &mut smallest)) {
+                    break;
+                }
+            }
+        }
+        loop {
+            let current = smallest.0.clone();
+            if !smaller_numbers(&current).any(|c| attempt(c, &mut smallest)) {
+                break;
+            }
+        }
+        if smallest.0 == start {
+            return smallest;
+        }
+    }
+}
+
+// `input` with a run of its pieces taken out, the biggest runs first
+fn without_some<'a>(input: &'a str, separator: &'a str) -> impl Iterator<Item = String> + 'a {
+    let pieces: Vec<&str> = input.split_inclusive(separator).collect();
+    let len = pieces.len();
+
+    (1..=len)
+        .rev()
+        .flat_map(move |run| (0..=len - run).step_by(run).map(move |at| (at, run)))
+        .map(move |(at, run)| {
+            let mut pieces = pieces.clone();
+            pieces.drain(at..at + run);
+            pieces.concat()
+        })
+}
+
+// `input` with one of its numbers swapped for a smaller one
+fn smaller_numbers(input: &str) -> impl Iterator<Item = String> + '_ {
+    let mut numbers = Vec::new();
+    let mut start = None;
+    for (i, c) in input.char_indices().chain([(input.len(), ' ')]) {
+        match (c.is_ascii_digit(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(from)) => {
+                numbers.push(from..i);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    numbers.into_iter().flat_map(move |at| {
+        let n: u128 = input[at.clone()].parse().unwrap_or_default();
+        let mut smaller = vec![0, 1, n / 2];
+        smaller.retain(|&m| m < n);
+        smaller.dedup();
+        smaller
+            .into_iter()
+            .map(move |m| format!("{}{m}{}", &input[..at.start], &input[at.end..]))
+    })
+}
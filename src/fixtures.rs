@@ -0,0 +1,41 @@
+use std::{fs, path::Path};
+
+use crate::aoc_days;
+
+// `-` skips a part, `@file` reads the expected answer from a file next to the input
+// and `?` always fails, for days that have been started but not worked through
+fn check_fixture(year: i32, day: i32, path: &str, part1: &str, part2: &str) {
+    let input = fs::read_to_string(path).unwrap();
+    let solver = aoc_days::solver(year, day).expect("day is not implemented");
+    let solved = solver(input, None).unwrap_or_else(|err| panic!("{err}"));
+
+    for (part, expected, actual) in [(1, part1, solved.part1), (2, part2, solved.part2)] {
+        let expected = match expected {
+            "-" => continue,
+            "?" => panic!("part {part} of {path} has no expected answer yet"),
+            expected => match expected.strip_prefix('@') {
+                Some(file) => fs::read_to_string(Path::new(path).with_file_name(file)).unwrap(),
+                None => expected.to_owned(),
+            },
+        };
+
+        assert_eq!(
+            normalise(&actual.to_string()),
+            normalise(&expected),
+            "part {part} of {path}"
+        );
+    }
+}
+
+// pictures may differ in trailing whitespace and surrounding blank lines
+fn normalise(answer: &str) -> String {
+    answer
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_matches('\n')
+        .to_owned()
+}
+
+include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
@@ -0,0 +1,62 @@
+//! Random inputs for stress testing, bigger or nastier than the real ones.
+//!
+//! Each day knows its own format through [`Solution::generate`](crate::solution::Solution::generate),
+//! this picks the day and seeds the generator so a seed always gives the same input.
+
+use std::{collections::HashSet, fmt::Display};
+
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::aoc_days;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenerateError {
+    NoSolution { year: i32, day: i32 },
+    NoGenerator { year: i32, day: i32 },
+}
+
+impl Display for GenerateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GenerateError::NoSolution { year, day } => {
+                write!(f, "there is no solution for {year} day {day}")
+            }
+            GenerateError::NoGenerator { year, day } => {
+                write!(f, "{year} day {day} can't generate inputs yet")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GenerateError {}
+
+/// The same generator for the same seed, on every platform.
+pub fn rng(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
+}
+
+/// A random input for `day` of about `size` lines or items.
+pub fn generate(year: i32, day: i32, seed: u64, size: usize) -> Result<String, GenerateError> {
+    let registration = aoc_days::find(year, day).ok_or(GenerateError::NoSolution { year, day })?;
+    (registration.generate)(&mut rng(seed), size).ok_or(GenerateError::NoGenerator { year, day })
+}
+
+/// `count` different names of `len` lowercase letters, none of them in `taken`.
+pub fn names(rng: &mut dyn RngCore, count: usize, len: usize, taken: &[&str]) -> Vec<String> {
+    assert!(
+        count + taken.len() <= 26usize.saturating_pow(len as u32),
+        "not enough names of {len} letters"
+    );
+    let mut names: Vec<String> = Vec::with_capacity(count);
+    let mut seen: HashSet<String> = taken.iter().map(|&name| name.to_owned()).collect();
+    while names.len() < count {
+        let name: String = (0..len)
+            .map(|_| rng.gen_range(b'a'..=b'z') as char)
+            .collect();
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    names
+}
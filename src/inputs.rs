@@ -0,0 +1,150 @@
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
+};
+
+use crate::client::{AocClient, ClientError};
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Io(PathBuf, io::Error),
+    Fetch(ClientError),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(f, "no input at {}", path.display()),
+            InputError::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+            InputError::Fetch(err) => write!(f, "fetching input failed: {}", err),
+        }
+    }
+}
+
+// shared by the threads of a parallel run
+pub trait InputSource: Sync {
+    fn load(&self, day: i32) -> Result<String, InputError>;
+}
+
+/// Inputs saved as `<root>/<year>/dayNN.txt`.
+pub struct CacheDir {
+    root: PathBuf,
+    year: i32,
+}
+
+impl CacheDir {
+    pub fn new(root: impl Into<PathBuf>, year: i32) -> Self {
+        CacheDir {
+            root: root.into(),
+            year,
+        }
+    }
+
+    pub fn path(&self, day: i32) -> PathBuf {
+        self.root
+            .join(self.year.to_string())
+            .join(format!("day{day:02}.txt"))
+    }
+
+    pub fn store(&self, day: i32, input: &str) -> Result<(), InputError> {
+        let path = self.path(day);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| InputError::Io(dir.to_owned(), err))?;
+        }
+        fs::write(&path, input).map_err(|err| InputError::Io(path, err))
+    }
+}
+
+impl InputSource for CacheDir {
+    fn load(&self, day: i32) -> Result<String, InputError> {
+        read_file(&self.path(day))
+    }
+}
+
+/// The worked example from each puzzle, saved as `<root>/<year>/dayNN/example.txt`.
+pub struct Examples {
+    pub root: PathBuf,
+    pub year: i32,
+}
+
+impl InputSource for Examples {
+    fn load(&self, day: i32) -> Result<String, InputError> {
+        read_file(
+            &self
+                .root
+                .join(self.year.to_string())
+                .join(format!("day{day:02}"))
+                .join("example.txt"),
+        )
+    }
+}
+
+/// A single file, used whatever day is asked for.
+pub struct InputFile(pub PathBuf);
+
+impl InputSource for InputFile {
+    fn load(&self, _day: i32) -> Result<String, InputError> {
+        read_file(&self.0)
+    }
+}
+
+pub struct Stdin;
+
+impl InputSource for Stdin {
+    fn load(&self, _day: i32) -> Result<String, InputError> {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|err| InputError::Io("<stdin>".into(), err))?;
+        Ok(input)
+    }
+}
+
+/// Downloads inputs from the website.
+pub struct Fetcher {
+    pub client: AocClient,
+    pub year: i32,
+}
+
+impl InputSource for Fetcher {
+    fn load(&self, day: i32) -> Result<String, InputError> {
+        // one download at a time, however many days run in parallel
+        static DOWNLOADING: Mutex<()> = Mutex::new(());
+        let _downloading = DOWNLOADING.lock().unwrap_or_else(PoisonError::into_inner);
+
+        log::debug!("downloading {} day {} input", self.year, day);
+
+        self.client.input(self.year, day).map_err(InputError::Fetch)
+    }
+}
+
+/// Reads from the cache, falling back to another source and caching what it returns.
+pub struct Cached<S> {
+    pub cache: CacheDir,
+    pub fallback: S,
+}
+
+impl<S: InputSource> InputSource for Cached<S> {
+    fn load(&self, day: i32) -> Result<String, InputError> {
+        match self.cache.load(day) {
+            Err(InputError::Missing(path)) => {
+                log::debug!("{} is not cached yet", path.display());
+                let input = self.fallback.load(day)?;
+                self.cache.store(day, &input)?;
+                Ok(input)
+            }
+            cached => cached,
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => InputError::Missing(path.to_owned()),
+        _ => InputError::Io(path.to_owned(), err),
+    })
+}
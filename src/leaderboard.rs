@@ -0,0 +1,337 @@
+use std::{
+    cmp::Reverse,
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::runner::table;
+
+/// A private leaderboard, as exported from its page's `[API]` link.
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: BTreeMap<String, Member>,
+    // only in newer exports
+    #[serde(default)]
+    day1_ts: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    // {day: {part: star}}
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+}
+
+#[derive(Debug)]
+pub enum LeaderboardError {
+    Io(PathBuf, io::Error),
+    Json(PathBuf, serde_json::Error),
+}
+
+impl Display for LeaderboardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LeaderboardError::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+            LeaderboardError::Json(path, err) => {
+                write!(f, "{} is not a leaderboard: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LeaderboardError {}
+
+/// How members are ranked against each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scoring {
+    // the site's own: each star is worth one point per member who got it later, plus one
+    Local,
+    // most stars, earliest last star breaking ties
+    Stars,
+    // local scoring of each day's time from part 1 to part 2
+    Delta,
+}
+
+/// A member's day, in seconds since the puzzle unlocked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Solve {
+    pub day: i32,
+    pub part1: i64,
+    pub part2: Option<i64>,
+}
+
+impl Solve {
+    pub fn delta(&self) -> Option<i64> {
+        self.part2.map(|part2| part2 - self.part1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub name: String,
+    pub stars: u32,
+    pub local: u32,
+    pub delta: u32,
+    // unix time, 0 without any stars
+    pub last_star: i64,
+}
+
+/// How a day went for the leaderboard as a whole.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayStats {
+    pub day: i32,
+    // members with each star
+    pub part1: usize,
+    pub part2: usize,
+    pub median_part1: Option<i64>,
+    pub median_part2: Option<i64>,
+    pub median_delta: Option<i64>,
+}
+
+impl Leaderboard {
+    pub fn load(path: &Path) -> Result<Self, LeaderboardError> {
+        let json =
+            fs::read_to_string(path).map_err(|err| LeaderboardError::Io(path.to_owned(), err))?;
+        serde_json::from_str(&json).map_err(|err| LeaderboardError::Json(path.to_owned(), err))
+    }
+
+    /// When `day`'s puzzle unlocked, as unix time.
+    pub fn unlock(&self, day: i32) -> i64 {
+        // midnight US Eastern on the 1st of December
+        let day1 = self.day1_ts.unwrap_or_else(|| {
+            let year = self.event.parse().unwrap_or_default();
+            days_from_civil(year, 12, 1) * 86400 + 5 * 3600
+        });
+        day1 + (day as i64 - 1) * 86400
+    }
+
+    pub fn solves(&self, member: &Member) -> Vec<Solve> {
+        let mut solves: Vec<Solve> = member
+            .completion_day_level
+            .iter()
+            .filter_map(|(day, parts)| {
+                let day = day.parse().ok()?;
+                let since = |part| Some(parts.get(part)?.get_star_ts - self.unlock(day));
+                Some(Solve {
+                    day,
+                    part1: since("1")?,
+                    part2: since("2"),
+                })
+            })
+            .collect();
+        solves.sort_by_key(|solve| solve.day);
+        solves
+    }
+
+    /// Every member, best first under `scoring`.
+    pub fn standings(&self, scoring: Scoring) -> Vec<Standing> {
+        let members = self.members.len() as u32;
+        let mut standings: BTreeMap<u64, Standing> = self
+            .members
+            .values()
+            .map(|member| {
+                let last_star = member
+                    .completion_day_level
+                    .values()
+                    .flat_map(BTreeMap::values)
+                    .map(|star| star.get_star_ts)
+                    .max()
+                    .unwrap_or(0);
+                let standing = Standing {
+                    name: member.display_name(),
+                    stars: member.stars,
+                    local: 0,
+                    delta: 0,
+                    last_star,
+                };
+                (member.id, standing)
+            })
+            .collect();
+
+        // (day, part) -> [(member, time)]; part 0 is the delta
+        let mut races: BTreeMap<(i32, u8), Vec<(u64, i64)>> = BTreeMap::new();
+        for member in self.members.values() {
+            for solve in self.solves(member) {
+                let times = [(1, Some(solve.part1)), (2, solve.part2), (0, solve.delta())];
+                for (part, time) in times {
+                    if let Some(time) = time {
+                        races
+                            .entry((solve.day, part))
+                            .or_default()
+                            .push((member.id, time));
+                    }
+                }
+            }
+        }
+
+        for ((_, part), mut finishers) in races {
+            finishers.sort_by_key(|&(id, time)| (time, id));
+            for (place, (id, _)) in finishers.into_iter().enumerate() {
+                let standing = standings.get_mut(&id).unwrap();
+                let points = members - place as u32;
+                match part {
+                    0 => standing.delta += points,
+                    _ => standing.local += points,
+                }
+            }
+        }
+
+        let mut standings: Vec<Standing> = standings.into_values().collect();
+        // a later last star loses ties, so the earliest last star sorts first
+        let tie = |s: &Standing| (s.last_star == 0, s.last_star);
+        match scoring {
+            Scoring::Local => standings.sort_by_key(|s| (Reverse(s.local), tie(s))),
+            Scoring::Stars => standings.sort_by_key(|s| (Reverse(s.stars), tie(s))),
+            Scoring::Delta => {
+                standings.sort_by_key(|s| (Reverse(s.delta), Reverse(s.local), tie(s)))
+            }
+        }
+        standings
+    }
+
+    /// Every day anyone has a star for, with how long it took the leaderboard.
+    pub fn days(&self) -> Vec<DayStats> {
+        let mut days: BTreeMap<i32, Vec<Solve>> = BTreeMap::new();
+        for member in self.members.values() {
+            for solve in self.solves(member) {
+                days.entry(solve.day).or_default().push(solve);
+            }
+        }
+
+        days.into_iter()
+            .map(|(day, solves)| {
+                let part2: Vec<i64> = solves.iter().filter_map(|s| s.part2).collect();
+                DayStats {
+                    day,
+                    part1: solves.len(),
+                    part2: part2.len(),
+                    median_part1: median(solves.iter().map(|s| s.part1).collect()),
+                    median_part2: median(part2),
+                    median_delta: median(solves.iter().filter_map(Solve::delta).collect()),
+                }
+            })
+            .collect()
+    }
+}
+
+impl Member {
+    /// The name the site shows, which is a placeholder for anonymous members.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+}
+
+/// Prints the standings, each day's stats and the timelines of the members whose
+/// name contains `member`, or everyone's.
+pub fn print_report(board: &Leaderboard, scoring: Scoring, member: Option<&str>) {
+    let rows: Vec<[String; 5]> = board
+        .standings(scoring)
+        .into_iter()
+        .enumerate()
+        .map(|(i, s)| {
+            [
+                (i + 1).to_string(),
+                s.name,
+                s.stars.to_string(),
+                s.local.to_string(),
+                s.delta.to_string(),
+            ]
+        })
+        .collect();
+    table(["#", "member", "stars", "local", "delta"], &rows);
+
+    let rows: Vec<[String; 6]> = board
+        .days()
+        .into_iter()
+        .map(|d| {
+            [
+                d.day.to_string(),
+                d.part1.to_string(),
+                d.part2.to_string(),
+                time(d.median_part1),
+                time(d.median_part2),
+                time(d.median_delta),
+            ]
+        })
+        .collect();
+    println!();
+    table(
+        [
+            "day",
+            "part 1 stars",
+            "part 2 stars",
+            "median part 1",
+            "median part 2",
+            "median delta",
+        ],
+        &rows,
+    );
+
+    let mut members: Vec<&Member> = board
+        .members
+        .values()
+        .filter(|m| match member {
+            Some(name) => m.display_name().contains(name),
+            None => true,
+        })
+        .collect();
+    members.sort_by_key(|m| m.display_name());
+
+    for m in members {
+        let rows: Vec<[String; 4]> = board
+            .solves(m)
+            .into_iter()
+            .map(|s| {
+                [
+                    s.day.to_string(),
+                    time(Some(s.part1)),
+                    time(s.part2),
+                    time(s.delta()),
+                ]
+            })
+            .collect();
+        println!("\n{} ({} stars)", m.display_name(), m.stars);
+        table(["day", "part 1", "part 2", "delta"], &rows);
+    }
+}
+
+// h:mm:ss, with as many hours as it took
+fn time(secs: Option<i64>) -> String {
+    match secs {
+        Some(secs) => format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60),
+        None => "-".to_owned(),
+    }
+}
+
+// the middle value, the later of the two for an even count
+fn median(mut values: Vec<i64>) -> Option<i64> {
+    values.sort_unstable();
+    values.get(values.len() / 2).copied()
+}
+
+// days since 1970-01-01 in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
@@ -0,0 +1,28 @@
+//! Advent of Code solutions and the tooling to run them.
+//!
+//! Every day implements [`solution::Solution`], so its parser and both parts can be
+//! used directly, e.g. `aoc::y2022::day13::Day13::parse`, alongside the types it
+//! parses into. The [`aoc_days`] registry finds a day by year and number, and the
+//! `aoc` binary is a thin wrapper around [`app::main`].
+
+pub mod answers;
+pub mod aoc_days;
+pub mod app;
+mod cli;
+pub mod client;
+pub mod differential;
+#[cfg(test)]
+mod fixtures;
+pub mod generate;
+pub mod inputs;
+pub mod leaderboard;
+pub mod logging;
+pub mod parse_error;
+pub mod puzzle;
+pub mod runner;
+mod scaffold;
+pub mod solution;
+pub mod submit;
+pub mod visualise;
+
+pub use aoc_days::y2022;
@@ -0,0 +1,78 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// Which diagnostics reach stderr, as set by `-v`, `-vv` and `--log-days`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Logging {
+    pub level: LevelFilter,
+    // only these days' own logs when given, the tooling's always get through
+    pub days: Option<Vec<i32>>,
+}
+
+impl Default for Logging {
+    fn default() -> Self {
+        Logging {
+            level: LevelFilter::Warn,
+            days: None,
+        }
+    }
+}
+
+impl Logging {
+    pub fn verbosity(count: usize) -> LevelFilter {
+        match count {
+            0 => LevelFilter::Warn,
+            1 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        }
+    }
+}
+
+/// Installs the logger, unless one is already installed.
+pub fn init(logging: Logging) {
+    let level = logging.level;
+    if log::set_boxed_logger(Box::new(Logger(logging))).is_ok() {
+        log::set_max_level(level);
+    }
+}
+
+struct Logger(Logging);
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        let target = metadata.target();
+        if metadata.level() > self.0.level {
+            return false;
+        }
+        // dependencies only get to warn, their debugging is not ours
+        if !target.starts_with("aoc") {
+            return metadata.level() <= Level::Warn;
+        }
+
+        match (&self.0.days, day_of(target)) {
+            (Some(days), Some((_, day))) => days.contains(&day),
+            _ => true,
+        }
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let source = match day_of(record.target()) {
+            Some((year, day)) => format!("{year} day {day}"),
+            None => record.target().trim_start_matches("aoc::").to_owned(),
+        };
+        eprintln!("{:<5} [{}] {}", record.level(), source, record.args());
+    }
+
+    fn flush(&self) {}
+}
+
+// the year and day of a target like `aoc::aoc_days::y2022::day8`
+fn day_of(target: &str) -> Option<(i32, i32)> {
+    let mut path = target.strip_prefix("aoc::aoc_days::")?.split("::");
+    let year = path.next()?.strip_prefix('y')?.parse().ok()?;
+    let day = path.next()?.strip_prefix("day")?.parse().ok()?;
+    Some((year, day))
+}
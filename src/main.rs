@@ -1,17 +1,5 @@
-mod aoc_days;
-use std::env::args;
+use std::{env::args, process::ExitCode};
 
-fn main() {
-    let args: Vec<String> = args().collect();
-
-    let day = args[1].parse::<i32>().unwrap();
-
-    match aoc_days::run_day(day) {
-        Err(err) => {
-            println!("{}", err)
-        }
-        Ok(_) => {
-            println!("{}", "done")
-        }
-    }
+fn main() -> ExitCode {
+    aoc::app::main(args().skip(1))
 }
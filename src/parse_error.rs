@@ -0,0 +1,163 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+/// Where and why a puzzle input could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ParseError {
+    pub year: i32,
+    pub day: i32,
+    // both 1-based
+    pub line: usize,
+    pub column: usize,
+    // the whole offending line, and how many characters of it to underline
+    pub text: String,
+    pub width: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error at `span`, which must be a slice of `input`.
+    pub fn at(input: &str, span: &str, expected: impl Into<String>) -> Self {
+        let offset = (span.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        // a CRLF line's `\r` is no more part of it than the `\n`
+        let text_end = match input[..line_end].ends_with('\r') {
+            true => line_end - 1,
+            false => line_end,
+        };
+        let on_line = text_end.saturating_sub(offset).min(span.len());
+
+        ParseError {
+            year: 0,
+            day: 0,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: input[line_start..text_end.max(line_start)].to_owned(),
+            width: span[..on_line].chars().count().max(1),
+            expected: expected.into(),
+        }
+    }
+
+    /// An error at the end of `input`.
+    pub fn at_end(input: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], expected)
+    }
+
+    pub fn nom(
+        input: &str,
+        err: nom::Err<nom::error::Error<&str>>,
+        expected: impl Into<String>,
+    ) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => Self::at(input, e.input, expected),
+            nom::Err::Incomplete(_) => Self::at_end(input, expected),
+        }
+    }
+
+    /// Fails if a parser stopped before the end of `input`, leaving `rest` behind.
+    pub fn finished(input: &str, rest: &str, expected: impl Into<String>) -> Result<(), Self> {
+        match rest.trim_start() {
+            "" => Ok(()),
+            rest => Err(Self::at(input, rest, expected)),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(
+            f,
+            "{} day {}, line {}, column {}: expected {}",
+            self.year, self.day, self.line, self.column, self.expected
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError;
+
+    fn caret(err: &ParseError) -> (usize, usize, &str, usize) {
+        (err.line, err.column, err.text.as_str(), err.width)
+    }
+
+    #[test]
+    fn points_at_the_line_and_column_of_the_span() {
+        let input = "1000\n2000\n\n30x0\n";
+        let err = ParseError::at(input, &input[13..14], "a number");
+
+        assert_eq!(caret(&err), (4, 3, "30x0", 1));
+        assert_eq!(
+            err.to_string(),
+            "0 day 0, line 4, column 3: expected a number\n  |\n4 | 30x0\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn underlines_the_whole_span_but_only_on_its_first_line() {
+        let input = "move 1 from 2 to 3\nmove x from 1 to 2\n";
+        let err = ParseError::at(input, &input[19..], "a move");
+
+        assert_eq!(caret(&err), (2, 1, "move x from 1 to 2", 18));
+    }
+
+    #[test]
+    fn crlf_lines_leave_out_the_carriage_return() {
+        let input = "1-2,3-4\r\n5-6,7-x\r\n";
+        let err = ParseError::finished(input, &input[15..], "a range").unwrap_err();
+
+        assert_eq!(caret(&err), (2, 7, "5-6,7-x", 1));
+
+        // right at the end of a line, on the `\r` itself
+        let err = ParseError::at(input, &input[7..], "the end of the line");
+        assert_eq!(caret(&err), (1, 8, "1-2,3-4", 1));
+    }
+
+    #[test]
+    fn columns_count_characters_not_bytes() {
+        let input = "Valve ÄÄ has flow rate=ö";
+        let at = input.find('ö').unwrap();
+        let err = ParseError::at(input, &input[at..], "a flow rate");
+
+        assert_eq!(caret(&err), (1, 24, input, 1));
+        assert!(err.to_string().ends_with(&format!("| {}^", " ".repeat(23))));
+    }
+
+    #[test]
+    fn at_the_end_of_the_input() {
+        let err = ParseError::at_end("[1,2]\n[3", "`]`");
+        assert_eq!(caret(&err), (2, 3, "[3", 1));
+
+        let err = ParseError::at_end("", "a packet");
+        assert_eq!(caret(&err), (1, 1, "", 1));
+    }
+
+    #[test]
+    fn finished_only_minds_more_than_whitespace() {
+        let input = "1,2\n\n";
+        assert_eq!(ParseError::finished(input, &input[3..], "nothing"), Ok(()));
+
+        let input = "1,2\n 3";
+        let err = ParseError::finished(input, &input[3..], "nothing").unwrap_err();
+        assert_eq!(caret(&err), (2, 2, " 3", 1));
+    }
+}
@@ -0,0 +1,346 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::client::{AocClient, ClientError};
+
+/// A day's puzzle statement, one article of HTML per part unlocked so far.
+#[derive(Debug, Clone)]
+pub struct Puzzle {
+    pub parts: Vec<String>,
+}
+
+#[derive(Debug)]
+pub enum PuzzleError {
+    // offline and nothing cached
+    Missing(PathBuf),
+    Io(PathBuf, io::Error),
+    Fetch(ClientError),
+    // a page without a puzzle on it, e.g. a login page
+    NoPuzzle,
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::Missing(path) => write!(f, "no puzzle at {}", path.display()),
+            PuzzleError::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+            PuzzleError::Fetch(err) => write!(f, "fetching puzzle failed: {}", err),
+            PuzzleError::NoPuzzle => f.write_str("the page has no puzzle on it"),
+        }
+    }
+}
+
+impl std::error::Error for PuzzleError {}
+
+impl Puzzle {
+    /// Picks the parts out of a whole puzzle page.
+    pub fn parse(page: &str) -> Option<Puzzle> {
+        let parts: Vec<String> = page
+            .split("<article")
+            .skip(1)
+            .filter_map(|rest| {
+                let (_, rest) = rest.split_once('>')?;
+                let (article, _) = rest.split_once("</article>")?;
+                Some(article.to_owned())
+            })
+            .collect();
+
+        (!parts.is_empty()).then_some(Puzzle { parts })
+    }
+
+    pub fn markdown(&self) -> String {
+        self.parts
+            .iter()
+            .map(|part| markdown(part))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The text of every `<pre><code>` block in order, the first usually being the example.
+    pub fn examples(&self) -> Vec<String> {
+        self.parts
+            .iter()
+            .flat_map(|part| between(part, "<pre><code>", "</code></pre>"))
+            .map(|block| decode(&strip_tags(block)))
+            .collect()
+    }
+
+    /// Each unlocked part's answer for the example, which the statement gives as its
+    /// last emphasised bit of code.
+    pub fn answers(&self) -> Vec<Option<String>> {
+        self.parts
+            .iter()
+            .map(|part| {
+                between(part, "<code><em>", "</em></code>")
+                    .last()
+                    .map(|answer| decode(&strip_tags(answer)))
+            })
+            .collect()
+    }
+}
+
+/// Saves `day`'s puzzle under `dir` as `<year>/dayNN.html` and `.md`, and fills in its
+/// example fixture under `examples`, returning the files it wrote. The cached page is
+/// used when it has both parts or there is no `client` to fetch a newer one with.
+pub fn fetch_puzzle(
+    client: Option<&AocClient>,
+    dir: &Path,
+    examples: &Path,
+    year: i32,
+    day: i32,
+) -> Result<Vec<PathBuf>, PuzzleError> {
+    let cached = dir.join(year.to_string()).join(format!("day{day:02}.html"));
+    let mut written = Vec::new();
+
+    let cached_puzzle = match fs::read_to_string(&cached) {
+        Ok(page) => Puzzle::parse(&page),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+        Err(err) => return Err(PuzzleError::Io(cached, err)),
+    };
+
+    let puzzle = match (cached_puzzle, client) {
+        (Some(puzzle), _) if puzzle.parts.len() >= 2 => puzzle,
+        (Some(puzzle), None) => puzzle,
+        (None, None) => return Err(PuzzleError::Missing(cached)),
+        (_, Some(client)) => {
+            let page = client.puzzle(year, day).map_err(PuzzleError::Fetch)?;
+            let puzzle = Puzzle::parse(&page).ok_or(PuzzleError::NoPuzzle)?;
+            write(&cached, &page)?;
+            written.push(cached.clone());
+            puzzle
+        }
+    };
+
+    let markdown = cached.with_extension("md");
+    write(&markdown, &puzzle.markdown())?;
+    written.push(markdown);
+
+    written.extend(write_fixture(
+        &puzzle,
+        &examples.join(year.to_string()).join(format!("day{day:02}")),
+    )?);
+
+    Ok(written)
+}
+
+// fills in the scaffolded `example.txt` and the `?`s of its answers, leaving anything
+// written by hand alone
+fn write_fixture(puzzle: &Puzzle, dir: &Path) -> Result<Vec<PathBuf>, PuzzleError> {
+    let mut written = Vec::new();
+
+    let example = dir.join("example.txt");
+    let current = fs::read_to_string(&example).unwrap_or_default();
+    if let (true, Some(input)) = (current.trim().is_empty(), puzzle.examples().first()) {
+        write(&example, input)?;
+        written.push(example);
+    }
+
+    let answers = dir.join("answers.txt");
+    let current = fs::read_to_string(&answers)
+        .unwrap_or_else(|_| "# input part1 part2\nexample.txt ? ?\n".to_owned());
+    let found = puzzle.answers();
+
+    let mut seen = false;
+    let mut lines: Vec<String> = current
+        .lines()
+        .map(|line| {
+            let mut fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.first() != Some(&"example.txt") {
+                return line.to_owned();
+            }
+            seen = true;
+            fields.resize(3, "?");
+            for (field, answer) in fields[1..].iter_mut().zip(&found) {
+                if let (&mut "?", Some(answer)) = (&mut *field, answer) {
+                    *field = answer.as_str();
+                }
+            }
+            fields.join(" ")
+        })
+        .collect();
+    if !seen {
+        let answer = |part: usize| found.get(part).cloned().flatten();
+        lines.push(format!(
+            "example.txt {} {}",
+            answer(0).unwrap_or_else(|| "?".to_owned()),
+            answer(1).unwrap_or_else(|| "?".to_owned())
+        ));
+    }
+
+    let updated = lines.join("\n") + "\n";
+    if updated != current {
+        write(&answers, &updated)?;
+        written.push(answers);
+    }
+
+    Ok(written)
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), PuzzleError> {
+    let err = |err| PuzzleError::Io(path.to_owned(), err);
+    fs::create_dir_all(path.parent().unwrap()).map_err(err)?;
+    fs::write(path, contents).map_err(err)
+}
+
+fn between<'a>(html: &'a str, open: &'a str, close: &'a str) -> impl Iterator<Item = &'a str> {
+    html.split(open)
+        .skip(1)
+        .filter_map(move |rest| rest.split_once(close).map(|(inner, _)| inner))
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(at) = rest.find('<') {
+        out.push_str(&rest[..at]);
+        rest = rest[at..].split_once('>').map_or("", |(_, rest)| rest);
+    }
+    out.push_str(rest);
+    out
+}
+
+fn decode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(at) = rest.find('&') {
+        out.push_str(&rest[..at]);
+        rest = &rest[at..];
+
+        let entity = rest.find(';').map(|end| &rest[1..end]);
+        let decoded = entity.and_then(|entity| match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => match entity.strip_prefix("#x") {
+                Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                None => entity.strip_prefix('#').and_then(|n| n.parse().ok()),
+            }
+            .and_then(char::from_u32),
+        });
+
+        match (entity, decoded) {
+            (Some(entity), Some(c)) => {
+                out.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// the statements only use a handful of tags, anything else just keeps its text
+fn markdown(html: &str) -> String {
+    let mut out = String::new();
+    // open tags, with their link and where their contents start in `out`
+    let mut open: Vec<(String, Option<String>, usize)> = Vec::new();
+    // <code><em>answer</em></code>, which can't be nested in markdown
+    let mut emphasised_code = false;
+
+    let mut rest = html;
+    while !rest.is_empty() {
+        let (text, tag) = match rest.find('<') {
+            Some(at) => {
+                let (text, tag) = rest.split_at(at);
+                match tag.split_once('>') {
+                    Some((tag, after)) => {
+                        rest = after;
+                        (text, Some(&tag[1..]))
+                    }
+                    None => {
+                        rest = "";
+                        (text, None)
+                    }
+                }
+            }
+            None => (std::mem::take(&mut rest), None),
+        };
+
+        if open.iter().any(|(name, ..)| name == "pre") {
+            out.push_str(&decode(text));
+        } else {
+            // html runs of whitespace are a single space, and none at the start of a line
+            for (i, word) in decode(text).split(char::is_whitespace).enumerate() {
+                if i > 0 && !(out.is_empty() || out.ends_with([' ', '\n'])) {
+                    out.push(' ');
+                }
+                out.push_str(word);
+            }
+        }
+
+        let Some(tag) = tag else { continue };
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        if !tag.starts_with('/') {
+            match name.as_str() {
+                "br" => out.push('\n'),
+                "img" | "hr" | "input" | "meta" | "link" => {}
+                _ => {
+                    let href = tag
+                        .split_once("href=\"")
+                        .and_then(|(_, href)| href.split_once('"'))
+                        .map(|(href, _)| href.to_owned());
+                    open.push((name, href, out.len()));
+                }
+            }
+            continue;
+        }
+
+        let Some(at) = open.iter().rposition(|(open, ..)| *open == name) else {
+            continue;
+        };
+        let (_, href, start) = open.remove(at);
+        open.truncate(at);
+        let content = out.split_off(start);
+        let in_pre = open.iter().any(|(name, ..)| name == "pre");
+        let in_code = open.iter().any(|(name, ..)| name == "code");
+
+        let converted = match name.as_str() {
+            _ if in_pre => content,
+            "pre" => {
+                let newline = if content.ends_with('\n') { "" } else { "\n" };
+                format!("```\n{content}{newline}```\n\n")
+            }
+            "code" if std::mem::take(&mut emphasised_code) => format!("**`{content}`**"),
+            "code" => format!("`{content}`"),
+            "em" if in_code => {
+                emphasised_code = true;
+                content
+            }
+            "em" => format!("*{content}*"),
+            "a" => match href {
+                Some(href) if href.starts_with('/') => {
+                    format!("[{content}]({}{href})", AocClient::DEFAULT_URL)
+                }
+                Some(href) => format!("[{content}]({href})"),
+                None => content,
+            },
+            "h2" => format!(
+                "## {}\n\n",
+                content.trim().trim_matches(|c| c == '-' || c == ' ')
+            ),
+            "p" => format!("{}\n\n", content.trim()),
+            "li" => format!("- {}\n", content.trim()),
+            "ul" | "ol" => format!("{}\n\n", content.trim_end()),
+            _ => content,
+        };
+        out.push_str(&converted);
+    }
+
+    out.trim().to_owned() + "\n"
+}
@@ -0,0 +1,419 @@
+use std::{
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+use serde::Serialize;
+
+use crate::{
+    aoc_days,
+    inputs::InputSource,
+    parse_error::ParseError,
+    solution::{Answer, Part, Timings},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    NotImplemented,
+    InputError(String),
+    ParseError(ParseError),
+    Panicked(String),
+}
+
+impl Status {
+    /// Whether the day went wrong, rather than ran or has nothing to run yet.
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Status::Ok | Status::NotImplemented)
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok => f.write_str("ok"),
+            Status::NotImplemented => f.write_str("not implemented"),
+            Status::InputError(_) => f.write_str("input error"),
+            Status::ParseError(_) => f.write_str("parse error"),
+            Status::Panicked(_) => f.write_str("panicked"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub year: i32,
+    pub day: i32,
+    pub part1: Answer,
+    pub part2: Answer,
+    pub status: Status,
+    // one entry per repetition
+    pub timings: Vec<Timings>,
+}
+
+impl DayReport {
+    fn failed(year: i32, day: i32, status: Status) -> Self {
+        DayReport {
+            year,
+            day,
+            part1: Answer::Unsolved,
+            part2: Answer::Unsolved,
+            status,
+            timings: Vec::new(),
+        }
+    }
+}
+
+pub fn run_day(
+    source: &dyn InputSource,
+    year: i32,
+    day: i32,
+    repeat: usize,
+    part: Option<Part>,
+) -> DayReport {
+    let Some(solver) = aoc_days::solver(year, day) else {
+        return DayReport::failed(year, day, Status::NotImplemented);
+    };
+
+    let input = match source.load(day) {
+        Ok(input) => input,
+        Err(err) => return DayReport::failed(year, day, Status::InputError(err.to_string())),
+    };
+
+    let mut report = DayReport::failed(year, day, Status::Ok);
+
+    for _ in 0..repeat.max(1) {
+        let input = input.clone();
+        match panic::catch_unwind(AssertUnwindSafe(|| solver(input, part))) {
+            Ok(Ok(solved)) => {
+                report.part1 = solved.part1;
+                report.part2 = solved.part2;
+                report.timings.push(solved.timings);
+            }
+            Ok(Err(err)) => return DayReport::failed(year, day, Status::ParseError(err)),
+            Err(payload) => {
+                return DayReport::failed(year, day, Status::Panicked(panic_message(payload)))
+            }
+        }
+    }
+
+    report
+}
+
+/// Runs `days` on up to `jobs` threads, returning the reports in the order the days were given.
+pub fn run_days(
+    source: &dyn InputSource,
+    year: i32,
+    days: &[i32],
+    repeat: usize,
+    part: Option<Part>,
+    jobs: usize,
+) -> Vec<DayReport> {
+    let next = AtomicUsize::new(0);
+
+    let mut reports: Vec<(usize, DayReport)> = thread::scope(|s| {
+        let workers: Vec<_> = (0..jobs.clamp(1, days.len().max(1)))
+            .map(|_| {
+                s.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(&day) = days.get(i) else {
+                            break done;
+                        };
+                        done.push((i, run_day(source, year, day, repeat, part)));
+                    }
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    });
+
+    reports.sort_by_key(|&(i, _)| i);
+    reports.into_iter().map(|(_, report)| report).collect()
+}
+
+pub(crate) fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}
+
+/// Parses a day selection such as `all`, `7` or `1-5,7,21`, keeping the order given
+/// but running each day only once.
+pub fn parse_days(selection: &str) -> Result<Vec<i32>, String> {
+    let mut days = Vec::new();
+
+    for part in selection.split(',') {
+        let day = |s: &str| {
+            s.trim()
+                .parse::<i32>()
+                .ok()
+                .filter(|d| (1..=25).contains(d))
+                .ok_or_else(|| format!("`{s}` is not a day between 1 and 25"))
+        };
+
+        let selected = match part.split_once('-') {
+            _ if part.trim() == "all" => 1..=25,
+            Some((from, to)) => match (day(from)?, day(to)?) {
+                (from, to) if from > to => {
+                    return Err(format!(
+                        "`{}` runs backwards, try `{to}-{from}`",
+                        part.trim()
+                    ))
+                }
+                (from, to) => from..=to,
+            },
+            None => day(part).map(|d| d..=d)?,
+        };
+
+        for d in selected {
+            if !days.contains(&d) {
+                days.push(d);
+            }
+        }
+    }
+
+    Ok(days)
+}
+
+pub fn print_table(reports: &[DayReport]) {
+    let header = [
+        "day",
+        "part 1",
+        "part 2",
+        "status",
+        "parse",
+        "part 1 time",
+        "part 2 time",
+        "total",
+    ];
+    let rows: Vec<[String; 8]> = reports
+        .iter()
+        .map(|r| {
+            [
+                r.day.to_string(),
+                cell(&r.part1),
+                cell(&r.part2),
+                r.status.to_string(),
+                spread(&r.timings, |t| t.parse),
+                spread(&r.timings, |t| t.part1),
+                spread(&r.timings, |t| t.part2),
+                spread(&r.timings, Timings::total),
+            ]
+        })
+        .collect();
+
+    table(header, &rows);
+
+    for r in reports {
+        for (part, answer) in [(1, &r.part1), (2, &r.part2)] {
+            if let Answer::Picture(picture) = answer {
+                println!("\nday {} part {}:\n{}", r.day, part, picture.trim_end());
+            }
+        }
+    }
+}
+
+/// Which days of each year have a solution, and for which parts.
+pub fn print_list() {
+    let registered = aoc_days::registered();
+    let rows: Vec<[String; 4]> = registered
+        .iter()
+        .map(|r| {
+            let part = |part| match r.parts.contains(&part) {
+                true => "yes".to_owned(),
+                false => "-".to_owned(),
+            };
+            [
+                r.year.to_string(),
+                r.day.to_string(),
+                part(Part::One),
+                part(Part::Two),
+            ]
+        })
+        .collect();
+
+    table(["year", "day", "part 1", "part 2"], &rows);
+
+    for year in aoc_days::years() {
+        let missing: Vec<_> = (1..=25)
+            .filter(|&day| !registered.iter().any(|r| (r.year, r.day) == (year, day)))
+            .map(|day| day.to_string())
+            .collect();
+        if !missing.is_empty() {
+            println!("\n{year} has no solution for day {}", missing.join(", "));
+        }
+    }
+}
+
+pub(crate) fn table<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let mut widths = header.map(str::len);
+    for row in rows {
+        for (w, c) in widths.iter_mut().zip(row) {
+            *w = (*w).max(c.chars().count());
+        }
+    }
+
+    let line = |cells: [&str; N]| {
+        cells
+            .iter()
+            .zip(widths)
+            .map(|(c, w)| format!("{c:<w$}"))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_owned()
+    };
+
+    println!("{}", line(header));
+    println!("{}", widths.map(|w| "-".repeat(w)).join("-+-"));
+    for row in rows {
+        println!("{}", line(row.each_ref().map(String::as_str)));
+    }
+}
+
+// pictures don't fit in a table cell so they get printed underneath
+fn cell(answer: &Answer) -> String {
+    match answer {
+        Answer::Picture(_) => "(picture)".to_owned(),
+        answer => answer.to_string(),
+    }
+}
+
+// median, with the min..max range when there is more than one run
+fn spread(timings: &[Timings], phase: impl Fn(&Timings) -> Duration) -> String {
+    let mut samples: Vec<Duration> = timings.iter().map(phase).collect();
+    samples.sort();
+
+    match samples.as_slice() {
+        [] => "-".to_owned(),
+        [only] => format!("{only:.2?}"),
+        [min, .., max] => format!("{:.2?} ({min:.2?}..{max:.2?})", samples[samples.len() / 2]),
+    }
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    year: i32,
+    day: i32,
+    status: String,
+    part1: &'a Answer,
+    part2: &'a Answer,
+    // one entry per repetition
+    timings: &'a [Timings],
+    error: Option<JsonError<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum JsonError<'a> {
+    Parse(&'a ParseError),
+    Message { message: &'a str },
+}
+
+/// One JSON object per line for each day.
+pub fn print_json(reports: &[DayReport]) {
+    for r in reports {
+        let error = match &r.status {
+            Status::ParseError(err) => Some(JsonError::Parse(err)),
+            Status::InputError(message) | Status::Panicked(message) => {
+                Some(JsonError::Message { message })
+            }
+            _ => None,
+        };
+
+        let report = JsonReport {
+            year: r.year,
+            day: r.day,
+            status: r.status.to_string(),
+            part1: &r.part1,
+            part2: &r.part2,
+            timings: &r.timings,
+            error,
+        };
+
+        println!("{}", serde_json::to_string(&report).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{parse_days, run_days, Status};
+    use crate::inputs::Examples;
+
+    #[test]
+    fn single_days_and_ranges() {
+        assert_eq!(parse_days("7"), Ok(vec![7]));
+        assert_eq!(parse_days("1-3,7, 21"), Ok(vec![1, 2, 3, 7, 21]));
+        assert_eq!(parse_days("5-5"), Ok(vec![5]));
+    }
+
+    #[test]
+    fn all_is_every_day_even_in_a_list() {
+        let every_day: Vec<i32> = (1..=25).collect();
+
+        assert_eq!(parse_days("all"), Ok(every_day.clone()));
+        assert_eq!(parse_days("all,5"), Ok(every_day));
+    }
+
+    #[test]
+    fn days_run_once_in_the_order_given() {
+        assert_eq!(parse_days("1,1"), Ok(vec![1]));
+        assert_eq!(parse_days("9,2-4,3,1"), Ok(vec![9, 2, 3, 4, 1]));
+    }
+
+    #[test]
+    fn backwards_ranges_are_an_error() {
+        assert_eq!(
+            parse_days("5-3"),
+            Err("`5-3` runs backwards, try `3-5`".to_owned())
+        );
+    }
+
+    #[test]
+    fn days_outside_december_are_an_error() {
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("1-26").is_err());
+        assert!(parse_days("x").is_err());
+        assert!(parse_days("").is_err());
+    }
+
+    #[test]
+    fn reports_come_back_in_the_order_given() {
+        let examples = Examples {
+            root: Path::new(env!("CARGO_MANIFEST_DIR")).join("examples"),
+            year: 2022,
+        };
+        // finishing at very different times, and one that fails straight away
+        let days = [24, 1, 13, 25, 8, 2, 14, 3, 10];
+
+        for jobs in [1, 2, 4, 16] {
+            let reports = run_days(&examples, 2022, &days, 1, None, jobs);
+
+            let order: Vec<i32> = reports.iter().map(|r| r.day).collect();
+            assert_eq!(order, days, "with {jobs} jobs");
+            for r in &reports {
+                let expected = match r.day {
+                    25 => Status::NotImplemented,
+                    _ => Status::Ok,
+                };
+                assert_eq!(r.status, expected, "day {} with {jobs} jobs", r.day);
+            }
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const TEMPLATE: &str = include_str!("aoc_days/template.rs");
+
+/// Creates the module, example fixture and failing test for a new day,
+/// returning the files it wrote.
+pub fn new_day(year: i32, day: i32) -> io::Result<Vec<PathBuf>> {
+    new_day_in(Path::new(""), year, day)
+}
+
+// `new_day` for the crate at `root`
+fn new_day_in(root: &Path, year: i32, day: i32) -> io::Result<Vec<PathBuf>> {
+    let src = root.join("src");
+    let days = src.join("aoc_days");
+    if !days.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "src/aoc_days not found, run this from the crate root",
+        ));
+    }
+
+    let module = days.join(format!("y{year}")).join(format!("day{day}.rs"));
+    if module.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module.display()),
+        ));
+    }
+
+    let mut written = Vec::new();
+
+    // the first day of a new year needs its own module too
+    let year_module = days.join(format!("y{year}.rs"));
+    if !year_module.exists() {
+        add_mod(&src.join("aoc_days.rs"), &format!("y{year}"))?;
+        // re-exported like the years before it, so it's `aoc::y{year}`
+        add_line(
+            &src.join("lib.rs"),
+            "pub use aoc_days::y",
+            &format!("pub use aoc_days::y{year};"),
+        )?;
+        fs::write(&year_module, "")?;
+        written.push(year_module.clone());
+    }
+    add_mod(&year_module, &format!("day{day}"))?;
+
+    fs::create_dir_all(module.parent().unwrap())?;
+    fs::write(&module, render(year, day))?;
+    written.push(module);
+
+    let examples = root
+        .join("examples")
+        .join(year.to_string())
+        .join(format!("day{day:02}"));
+    fs::create_dir_all(&examples)?;
+
+    let example = examples.join("example.txt");
+    if !example.exists() {
+        fs::write(&example, "")?;
+        written.push(example);
+    }
+
+    // `?` fails the generated test until the real answers are filled in
+    let answers = examples.join("answers.txt");
+    if !answers.exists() {
+        fs::write(&answers, "# input part1 part2\nexample.txt ? ?\n")?;
+        written.push(answers);
+    }
+
+    Ok(written)
+}
+
+fn render(year: i32, day: i32) -> String {
+    let newline = if TEMPLATE.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    // without the template's own header comment
+    let body: String = TEMPLATE
+        .split_inclusive('\n')
+        .skip_while(|l| l.starts_with("//") || l.trim().is_empty())
+        .collect();
+
+    body.replacen(
+        "use crate::{",
+        &format!("use crate::{{{newline}    aoc_days::register,"),
+        1,
+    )
+    .replacen(
+        "pub struct DayN;",
+        &format!("pub struct DayN;{newline}{newline}register!(DayN);"),
+        1,
+    )
+    .replace("DayN", &format!("Day{day}"))
+    .replace(
+        "const YEAR: i32 = 0;",
+        &format!("const YEAR: i32 = {year};"),
+    )
+    .replace("const DAY: i32 = 0;", &format!("const DAY: i32 = {day};"))
+}
+
+// slots `pub mod <name>;` in among its siblings, in the order rustfmt keeps them
+fn add_mod(file: &Path, name: &str) -> io::Result<()> {
+    let prefix = name.trim_end_matches(|c: char| c.is_ascii_digit());
+    add_line(
+        file,
+        &format!("pub mod {prefix}"),
+        &format!("pub mod {name};"),
+    )
+}
+
+// slots `line` in among its siblings, the lines starting with `prefix`, sorted by what
+// follows it, or at the end without any, keeping the file's line endings
+fn add_line(file: &Path, prefix: &str, line: &str) -> io::Result<()> {
+    let contents = fs::read_to_string(file)?;
+    let newline = if contents.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let mut lines: Vec<&str> = contents.lines().collect();
+
+    let key = |l: &str| l[prefix.len()..].trim_end_matches(';').to_owned();
+    let siblings: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with(prefix))
+        .collect();
+
+    let at = siblings
+        .iter()
+        .copied()
+        .find(|&i| key(lines[i]) > key(line))
+        .or(siblings.last().map(|&i| i + 1))
+        .unwrap_or(lines.len());
+    lines.insert(at, line);
+
+    fs::write(file, lines.join(newline) + newline)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf};
+
+    use super::new_day_in;
+    use crate::aoc_days;
+
+    #[test]
+    fn the_template_is_not_a_registered_day() {
+        assert!(aoc_days::registered().iter().all(|r| r.year != 0));
+        assert!(!aoc_days::years().contains(&0));
+    }
+
+    // a crate with just enough of this one's layout to scaffold into
+    fn crate_at(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/aoc_days/y2022")).unwrap();
+        fs::write(
+            root.join("src/aoc_days.rs"),
+            "#[cfg(test)]\r\nmod template;\r\npub mod y2022;\r\n\r\nuse std::any::Any;\r\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/lib.rs"),
+            "pub mod aoc_days;\nmod scaffold;\n\npub use aoc_days::y2022;\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/aoc_days/y2022.rs"),
+            "pub mod day1;\npub mod day2;\npub mod day24;\n",
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn new_days_go_among_their_siblings() {
+        let root = crate_at("day");
+
+        new_day_in(&root, 2022, 18).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(root.join("src/aoc_days/y2022.rs")).unwrap(),
+            "pub mod day1;\npub mod day18;\npub mod day2;\npub mod day24;\n"
+        );
+        let module = fs::read_to_string(root.join("src/aoc_days/y2022/day18.rs")).unwrap();
+        assert!(module.contains("const YEAR: i32 = 2022;"));
+        assert!(module.contains("const DAY: i32 = 18;"));
+        assert!(module.contains("    aoc_days::register,\r\n"));
+        assert!(module.contains("pub struct Day18;\r\n\r\nregister!(Day18);\r\n"));
+        assert!(!module.contains("DayN"));
+        assert_eq!(
+            fs::read_to_string(root.join("examples/2022/day18/answers.txt")).unwrap(),
+            "# input part1 part2\nexample.txt ? ?\n"
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn a_new_year_is_declared_and_reexported() {
+        let root = crate_at("year");
+
+        new_day_in(&root, 2023, 1).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(root.join("src/aoc_days.rs")).unwrap(),
+            "#[cfg(test)]\r\nmod template;\r\npub mod y2022;\r\npub mod y2023;\r\n\r\nuse std::any::Any;\r\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+            "pub mod aoc_days;\nmod scaffold;\n\npub use aoc_days::y2022;\npub use aoc_days::y2023;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/aoc_days/y2023.rs")).unwrap(),
+            "pub mod day1;\n"
+        );
+        assert!(root.join("src/aoc_days/y2023/day1.rs").exists());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
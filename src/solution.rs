@@ -0,0 +1,196 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use rand::RngCore;
+use serde::{Serialize, Serializer};
+
+use crate::parse_error::ParseError;
+
+/// The result of solving one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    // multi-line output that has to be read by eye, e.g. day 10's CRT
+    Picture(String),
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(x) => write!(f, "{x}"),
+            Answer::Text(s) => f.write_str(s),
+            Answer::Picture(p) => write!(f, "\n{}", p.trim_end()),
+            Answer::Unsolved => f.write_str("-"),
+        }
+    }
+}
+
+// numbers stay numbers, unsolved parts become null
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(x) => serializer.serialize_i64(*x),
+            Answer::Text(s) | Answer::Picture(s) => serializer.serialize_str(s),
+            Answer::Unsolved => serializer.serialize_none(),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(x: i32) -> Self {
+        Answer::Number(x.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(x: i64) -> Self {
+        Answer::Number(x)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(x: usize) -> Self {
+        Answer::Number(x as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_owned())
+    }
+}
+
+/// One question put to both a slow reference solver and the real solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    // e.g. `part 2`
+    pub what: String,
+    pub reference: Answer,
+    pub solution: Answer,
+}
+
+impl Comparison {
+    pub fn agrees(&self) -> bool {
+        self.reference == self.solution
+    }
+}
+
+/// One half of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+pub trait Solution {
+    const YEAR: i32;
+    const DAY: i32;
+    // the parts with a solution so far, as shown by `aoc list`
+    const PARTS: &'static [Part] = &[Part::One, Part::Two];
+
+    type Input;
+
+    fn parse(input: String) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// A random input that parses and can be solved, of about `size` lines or items,
+    /// for `aoc gen`. Days without a generator give `None`.
+    fn generate(_rng: &mut dyn RngCore, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// What an obviously correct but slow method makes of `input`, next to the solution's
+    /// own answers, for `aoc diff`. Empty when the input is out of the reference's reach,
+    /// and `None` for days without one.
+    fn reference(_input: &Self::Input) -> Option<Vec<Comparison>> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct Timings {
+    #[serde(rename = "parse_ns", serialize_with = "nanos")]
+    pub parse: Duration,
+    #[serde(rename = "part1_ns", serialize_with = "nanos")]
+    pub part1: Duration,
+    #[serde(rename = "part2_ns", serialize_with = "nanos")]
+    pub part2: Duration,
+}
+
+fn nanos<S: Serializer>(d: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(d.as_nanos() as u64)
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub part1: Answer,
+    pub part2: Answer,
+    pub timings: Timings,
+}
+
+/// Parses `input` and runs both parts, or just `only` if given.
+/// A part that is skipped stays `Unsolved` with a zero timing.
+pub fn solve<S: Solution>(input: String, only: Option<Part>) -> Result<Solved, ParseError> {
+    let start = Instant::now();
+    let input = parse::<S>(input)?;
+
+    let mut solved = Solved {
+        part1: Answer::Unsolved,
+        part2: Answer::Unsolved,
+        timings: Timings {
+            parse: start.elapsed(),
+            ..Timings::default()
+        },
+    };
+
+    if only != Some(Part::Two) {
+        let start = Instant::now();
+        solved.part1 = S::part1(&input);
+        solved.timings.part1 = start.elapsed();
+    }
+
+    if only != Some(Part::One) {
+        let start = Instant::now();
+        solved.part2 = S::part2(&input);
+        solved.timings.part2 = start.elapsed();
+    }
+
+    Ok(solved)
+}
+
+/// [`Solution::parse`], with errors saying which day they came from.
+pub fn parse<S: Solution>(input: String) -> Result<S::Input, ParseError> {
+    S::parse(input).map_err(|err| ParseError {
+        year: S::YEAR,
+        day: S::DAY,
+        ..err
+    })
+}
@@ -0,0 +1,60 @@
+use std::{fmt::Display, io};
+
+use crate::{
+    answers::AnswerStore,
+    client::{AocClient, ClientError, Verdict},
+    solution::{Answer, Part},
+};
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Unsolved,
+    // pictures have to be read by eye and submitted by hand
+    Picture,
+    KnownWrong(String),
+    Client(ClientError),
+    Store(io::Error),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::Unsolved => f.write_str("there is no answer to submit"),
+            SubmitError::Picture => f.write_str("the answer is a picture, submit what it spells"),
+            SubmitError::KnownWrong(reason) => write!(f, "not submitting, {}", reason),
+            SubmitError::Client(err) => write!(f, "submitting failed: {}", err),
+            SubmitError::Store(err) => write!(f, "saving the answer failed: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+/// Submits `answer` unless `store` already knows it is wrong, and remembers the verdict.
+pub fn submit(
+    client: &AocClient,
+    store: &mut AnswerStore,
+    year: i32,
+    day: i32,
+    part: Part,
+    answer: &Answer,
+) -> Result<Verdict, SubmitError> {
+    let answer = match answer {
+        Answer::Unsolved => return Err(SubmitError::Unsolved),
+        Answer::Picture(_) => return Err(SubmitError::Picture),
+        answer => answer.to_string(),
+    };
+
+    if let Some(reason) = store.known_wrong(day, part.number(), &answer) {
+        return Err(SubmitError::KnownWrong(reason));
+    }
+
+    let verdict = client
+        .submit(year, day, part, &answer)
+        .map_err(SubmitError::Client)?;
+
+    store.remember(day, part.number(), &answer, verdict);
+    store.save().map_err(SubmitError::Store)?;
+
+    Ok(verdict)
+}
@@ -0,0 +1,230 @@
+//! Pictures of a simulation as it runs.
+//!
+//! Days [`emit`] frames as they go, which costs nothing unless a [`FrameSink`] has been
+//! [`install`]ed, e.g. by `--visualise`.
+
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex, PoisonError,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+pub mod export;
+
+/// One picture of a grid, a character per cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    // e.g. which minute of the simulation this is
+    pub caption: String,
+    width: usize,
+    cells: Vec<char>,
+}
+
+impl Frame {
+    /// A `width` by `height` frame of blank cells.
+    pub fn new(caption: impl Into<String>, width: usize, height: usize) -> Self {
+        Frame {
+            caption: caption.into(),
+            width,
+            cells: vec![' '; width * height],
+        }
+    }
+
+    /// A frame of the lines of `text`, padding short lines with blanks.
+    pub fn from_text(caption: impl Into<String>, text: &str) -> Self {
+        let width = text.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut frame = Frame::new(caption, width, text.lines().count());
+        for (y, line) in text.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                frame.set(x, y, c);
+            }
+        }
+        frame
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        match self.width {
+            0 => 0,
+            width => self.cells.len() / width,
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        if x < self.width {
+            self.cells.get(y * self.width + x).copied()
+        } else {
+            None
+        }
+    }
+
+    /// Sets a cell, ignoring any outside the frame.
+    pub fn set(&mut self, x: usize, y: usize, c: char) {
+        if x < self.width {
+            if let Some(cell) = self.cells.get_mut(y * self.width + x) {
+                *cell = c;
+            }
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[char]> {
+        self.cells.chunks(self.width.max(1))
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>().trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+/// Somewhere frames go.
+// shared by whichever thread is running a day
+pub trait FrameSink: Send {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()>;
+
+    /// Called once the run is over.
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Each frame as plain text under its caption, with a blank line between frames.
+pub struct TextSink<W>(pub W);
+
+impl<W: Write + Send> FrameSink for TextSink<W> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        writeln!(self.0, "{}\n{}", frame.caption, frame)
+    }
+}
+
+/// Frames redrawn in place on a terminal, in colour, at most `fps` a second.
+pub struct AnsiSink<W> {
+    out: W,
+    interval: Duration,
+    last: Option<Instant>,
+}
+
+impl<W> AnsiSink<W> {
+    pub fn new(out: W, fps: u32) -> Self {
+        AnsiSink {
+            out,
+            interval: Duration::from_secs(1) / fps.max(1),
+            last: None,
+        }
+    }
+}
+
+impl<W: Write + Send> FrameSink for AnsiSink<W> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        if let Some(last) = self.last {
+            thread::sleep(self.interval.saturating_sub(last.elapsed()));
+        }
+        self.last = Some(Instant::now());
+
+        // home the cursor and clear the screen, then draw from the top
+        let mut screen = format!("\x1b[H\x1b[2J{}\n", frame.caption);
+        for row in frame.rows() {
+            let mut colour = None;
+            for &c in row {
+                if ansi_colour(c) != colour {
+                    colour = ansi_colour(c);
+                    screen += &format!("\x1b[{}m", colour.unwrap_or(0));
+                }
+                screen.push(c);
+            }
+            screen += "\x1b[0m\n";
+        }
+
+        self.out.write_all(screen.as_bytes())?;
+        self.out.flush()
+    }
+}
+
+// the foreground colour for the characters days draw with
+fn ansi_colour(c: char) -> Option<u8> {
+    match c {
+        '#' => Some(97),
+        '.' => Some(90),
+        'E' | '@' => Some(92),
+        'o' | '~' | '+' => Some(93),
+        '^' | 'v' | '<' | '>' | '1'..='9' => Some(96),
+        _ => None,
+    }
+}
+
+/// Takes every frame and does nothing with it, to time drawing them.
+pub struct NullSink;
+
+impl FrameSink for NullSink {
+    fn frame(&mut self, _: &Frame) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+static SINK: Mutex<Option<Box<dyn FrameSink>>> = Mutex::new(None);
+// checked before drawing anything, so frames cost nothing without a sink
+static ENABLED: AtomicBool = AtomicBool::new(false);
+// only every `EVERY`th frame emitted since the sink went in is drawn
+static EVERY: AtomicUsize = AtomicUsize::new(1);
+static EMITTED: AtomicUsize = AtomicUsize::new(0);
+
+/// Sends every frame emitted from now on to `sink`.
+pub fn install(sink: Box<dyn FrameSink>) {
+    install_every(sink, 1);
+}
+
+/// Sends the next frame emitted and every `every`th one after it to `sink`, without
+/// drawing the rest, for simulations with more frames than are worth keeping.
+pub fn install_every(sink: Box<dyn FrameSink>, every: usize) {
+    *SINK.lock().unwrap_or_else(PoisonError::into_inner) = Some(sink);
+    EVERY.store(every.max(1), Ordering::Relaxed);
+    EMITTED.store(0, Ordering::Relaxed);
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Takes out the installed sink, if any, and finishes it.
+pub fn finish() -> io::Result<()> {
+    ENABLED.store(false, Ordering::Relaxed);
+    let sink = SINK.lock().unwrap_or_else(PoisonError::into_inner).take();
+    match sink {
+        Some(mut sink) => sink.finish(),
+        None => Ok(()),
+    }
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Draws a frame and sends it to the installed sink, if there is one.
+pub fn emit(frame: impl FnOnce() -> Frame) {
+    if !enabled()
+        || !EMITTED
+            .fetch_add(1, Ordering::Relaxed)
+            .is_multiple_of(EVERY.load(Ordering::Relaxed))
+    {
+        return;
+    }
+
+    let frame = frame();
+    let mut sink = SINK.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(sink) = sink.as_mut() {
+        // a broken pipe shouldn't stop the solution
+        if let Err(err) = sink.frame(&frame) {
+            log::warn!("dropping frames: {}", err);
+            ENABLED.store(false, Ordering::Relaxed);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+//! Frames as images, for pasting where terminal text won't go.
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fs::{self, File},
+    io::{self, BufWriter, Seek, SeekFrom, Write},
+    path::PathBuf,
+};
+
+use super::{Frame, FrameSink};
+
+/// The colour each character is drawn in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colours: HashMap<char, [u8; 3]>,
+    // for characters without a colour of their own
+    other: [u8; 3],
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        let background = [0x0f, 0x0f, 0x23];
+        let colours = [
+            ("#", [0xcc, 0xcc, 0xcc]),
+            (". ", background),
+            ("E@", [0x00, 0xcc, 0x00]),
+            ("o~+", [0xff, 0xff, 0x66]),
+            ("^v<>123456789", [0x99, 0x99, 0xcc]),
+        ];
+
+        Palette {
+            colours: colours
+                .into_iter()
+                .flat_map(|(cs, colour)| cs.chars().map(move |c| (c, colour)))
+                .collect(),
+            other: [0xff, 0x66, 0x66],
+        }
+    }
+}
+
+impl Palette {
+    /// The default palette with some colours replaced, given like `#=ffffff,o=c0a000`.
+    pub fn parse(spec: &str) -> Result<Palette, String> {
+        let mut palette = Palette::default();
+
+        for entry in spec.split(',').filter(|e| !e.is_empty()) {
+            let mut chars = entry.chars();
+            let (Some(c), Some('=')) = (chars.next(), chars.next()) else {
+                return Err(format!("`{entry}` is not like `#=ffffff`"));
+            };
+            let hex = chars.as_str();
+            let colour = match (hex.len(), u32::from_str_radix(hex, 16)) {
+                (6, Ok(rgb)) => [(rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8],
+                _ => return Err(format!("`{hex}` is not a colour like `ffffff`")),
+            };
+            palette.colours.insert(c, colour);
+        }
+
+        Ok(palette)
+    }
+
+    pub fn colour(&self, c: char) -> [u8; 3] {
+        self.colours.get(&c).copied().unwrap_or(self.other)
+    }
+
+    // every colour once, for indexed images
+    fn table(&self) -> Vec<[u8; 3]> {
+        let mut table: Vec<[u8; 3]> = self.colours.values().copied().collect();
+        table.push(self.other);
+        table.sort_unstable();
+        table.dedup();
+        table
+    }
+}
+
+/// Each frame as its own PNG, numbered in order in `dir`.
+pub struct PngSink {
+    dir: PathBuf,
+    palette: Palette,
+    // pixels along each side of a cell
+    cell: usize,
+    written: usize,
+}
+
+impl PngSink {
+    pub fn new(dir: impl Into<PathBuf>, palette: Palette, cell: usize) -> Self {
+        PngSink {
+            dir: dir.into(),
+            palette,
+            cell: cell.max(1),
+            written: 0,
+        }
+    }
+}
+
+impl FrameSink for PngSink {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        self.written += 1;
+        let path = self.dir.join(format!("frame{:05}.png", self.written));
+
+        let (width, height) = (frame.width() * self.cell, frame.height() * self.cell);
+        let pixels: Vec<u8> = scale(frame, self.cell, |c| self.palette.colour(c))
+            .into_iter()
+            .flatten()
+            .collect();
+
+        let mut encoder = png::Encoder::new(
+            BufWriter::new(File::create(path)?),
+            width as u32,
+            height as u32,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&pixels))
+            .map_err(io::Error::other)
+    }
+}
+
+/// A whole run as one animated GIF, looping at `fps` frames a second.
+pub struct GifSink {
+    path: PathBuf,
+    palette: Palette,
+    cell: usize,
+    fps: u32,
+    // opened at the first frame, and each frame is written as it comes
+    encoder: Option<gif::Encoder<BufWriter<File>>>,
+    // the largest frame so far in cells, which every later one is padded out to
+    width: usize,
+    height: usize,
+    // where each ASCII character's colour is in the palette's table, as there are a lot
+    // of cells to look up
+    ascii: Vec<u8>,
+}
+
+impl GifSink {
+    pub fn new(path: impl Into<PathBuf>, palette: Palette, cell: usize, fps: u32) -> Self {
+        GifSink {
+            path: path.into(),
+            palette,
+            cell: cell.max(1),
+            fps: fps.max(1),
+            encoder: None,
+            width: 0,
+            height: 0,
+            ascii: Vec::new(),
+        }
+    }
+
+    fn index(&self, table: &[[u8; 3]], c: char) -> u8 {
+        match self.ascii.get(c as usize) {
+            Some(&i) => i,
+            None => {
+                let colour = self.palette.colour(c);
+                table.iter().position(|&t| t == colour).unwrap() as u8
+            }
+        }
+    }
+
+    // in pixels, which a GIF only has 16 bits for
+    fn size(&self, cells: usize) -> io::Result<u16> {
+        u16::try_from(cells * self.cell).map_err(|_| io::Error::other("too big for a GIF"))
+    }
+}
+
+impl FrameSink for GifSink {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let gif_err = |err: gif::EncodingError| io::Error::other(err);
+        let table = self.palette.table();
+        if table.len() > 256 {
+            return Err(io::Error::other("a GIF can't have more than 256 colours"));
+        }
+        if self.ascii.is_empty() {
+            self.ascii = (0..128u8).map(|c| self.index(&table, c as char)).collect();
+        }
+
+        // frames of a growing simulation are drawn from the top left of the largest yet
+        self.width = self.width.max(frame.width());
+        self.height = self.height.max(frame.height());
+        let (width, height) = (self.size(self.width)?, self.size(self.height)?);
+
+        let mut buffer = vec![self.index(&table, ' '); width as usize * height as usize];
+        for (y, row) in scale(frame, self.cell, |c| self.index(&table, c))
+            .chunks((frame.width() * self.cell).max(1))
+            .enumerate()
+        {
+            let start = y * width as usize;
+            buffer[start..start + row.len()].copy_from_slice(row);
+        }
+
+        let encoder = match &mut self.encoder {
+            Some(encoder) => encoder,
+            None => {
+                let flat: Vec<u8> = table.iter().flatten().copied().collect();
+                let out = BufWriter::new(File::create(&self.path)?);
+                // the screen is made as big as the largest frame once they've all been seen
+                let mut encoder = gif::Encoder::new(out, width, height, &flat).map_err(gif_err)?;
+                encoder.set_repeat(gif::Repeat::Infinite).map_err(gif_err)?;
+                self.encoder.insert(encoder)
+            }
+        };
+
+        let frame = gif::Frame {
+            width,
+            height,
+            // hundredths of a second
+            delay: (100 / self.fps).max(1) as u16,
+            buffer: Cow::Owned(buffer),
+            ..gif::Frame::default()
+        };
+        encoder.write_frame(&frame).map_err(gif_err)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let Some(encoder) = self.encoder.take() else {
+            return Ok(());
+        };
+        let mut file = encoder
+            .into_inner()?
+            .into_inner()
+            .map_err(io::IntoInnerError::into_error)?;
+
+        // the screen's width and height follow the six byte signature
+        let (width, height) = (self.size(self.width)?, self.size(self.height)?);
+        file.seek(SeekFrom::Start(6))?;
+        file.write_all(&[width.to_le_bytes(), height.to_le_bytes()].concat())
+    }
+}
+
+// every pixel of `frame`, row by row, with each cell drawn `cell` pixels square
+fn scale<T: Copy>(frame: &Frame, cell: usize, pixel: impl Fn(char) -> T) -> Vec<T> {
+    let mut pixels = Vec::with_capacity(frame.width() * frame.height() * cell * cell);
+    for row in frame.rows() {
+        let line: Vec<T> = row
+            .iter()
+            .flat_map(|&c| std::iter::repeat_n(pixel(c), cell))
+            .collect();
+        for _ in 0..cell {
+            pixels.extend_from_slice(&line);
+        }
+    }
+    pixels
+}
@@ -0,0 +1,38 @@
+mod common;
+
+use std::{fs, process::Command};
+
+use common::scratch;
+
+fn aoc(args: &[&str]) -> bool {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .output()
+        .unwrap()
+        .status
+        .success()
+}
+
+#[test]
+fn days_that_ran_or_have_no_solution_succeed() {
+    let example = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/2022/day01/example.txt"
+    );
+
+    assert!(aoc(&["1", "--input", example]));
+    assert!(aoc(&["25", "--input", example]));
+}
+
+#[test]
+fn a_day_that_went_wrong_fails_the_run() {
+    let garbage = scratch("cli-garbage.txt");
+    fs::write(&garbage, "not a calorie count\n").unwrap();
+
+    let failed = !aoc(&["1", "--input", garbage.to_str().unwrap()]);
+    let missing = !aoc(&["1", "--input", "no/such/input.txt"]);
+
+    assert!(failed, "a parse error should fail the run");
+    assert!(missing, "an input error should fail the run");
+    fs::remove_file(garbage).unwrap();
+}
@@ -0,0 +1,171 @@
+// A stand-in for the Advent of Code website, serving files from `tests/fixtures/aoc`,
+// and the odd helper shared between tests.
+
+// each test file only uses some of it
+#![allow(dead_code)]
+
+use std::{
+    collections::HashSet,
+    env, fs,
+    io::Cursor,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use aoc::client::AocClient;
+use tiny_http::{Header, Request, Response, Server};
+
+// the only session the server accepts
+pub const SESSION: &str = "test-session";
+// a session that is always told to slow down
+pub const RATE_LIMITED: &str = "rate-limited";
+// a session whose last wrong answer was too recent to answer again
+pub const COOLING_DOWN: &str = "cooling-down";
+
+// the (year, day, level) answered right so far
+type Solved = Arc<Mutex<HashSet<(String, String, String)>>>;
+
+pub struct MockAoc {
+    url: String,
+}
+
+impl MockAoc {
+    pub fn start() -> Self {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+
+        thread::spawn(move || {
+            let solved = Solved::default();
+            for mut request in server.incoming_requests() {
+                let response = respond(&mut request, &solved);
+                let _ = request.respond(response);
+            }
+        });
+
+        MockAoc { url }
+    }
+
+    pub fn client(&self, session: Option<&str>) -> AocClient {
+        AocClient::new(&self.url, session.map(str::to_owned))
+    }
+}
+
+// somewhere in the temp dir for a test to write to, with nothing there yet
+pub fn scratch(name: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    let _ = fs::remove_file(&path);
+    path
+}
+
+pub fn fixture(path: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/aoc")
+        .join(path)
+}
+
+fn respond(request: &mut Request, solved: &Solved) -> Response<Cursor<Vec<u8>>> {
+    let session = request
+        .headers()
+        .iter()
+        .find(|h| h.field.equiv("Cookie"))
+        .and_then(|h| h.value.as_str().strip_prefix("session="))
+        .map(str::to_owned);
+
+    match session.as_deref() {
+        Some(SESSION) => {}
+        Some(COOLING_DOWN) => {
+            return article(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 4s left to wait.",
+            )
+        }
+        Some(RATE_LIMITED) => {
+            return text(429, "Too Many Requests")
+                .with_header(Header::from_bytes("Retry-After", "30").unwrap())
+        }
+        _ => {
+            return text(
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            )
+        }
+    }
+
+    let url = request.url().to_owned();
+    let path: Vec<&str> = url.trim_matches('/').split('/').collect();
+    match path[..] {
+        [year, "day", day, "input"] => {
+            match fs::read_to_string(fixture(&format!("{year}/day{day}/input.txt"))) {
+                Ok(input) => text(200, &input),
+                Err(_) => text(
+                    404,
+                    "Please don't repeatedly request this endpoint before it unlocks!",
+                ),
+            }
+        }
+        [year, "day", day] => {
+            match fs::read_to_string(fixture(&format!("{year}/day{day}/puzzle.html"))) {
+                Ok(page) => Response::from_string(page)
+                    .with_header(Header::from_bytes("Content-Type", "text/html").unwrap()),
+                Err(_) => text(404, "404 Not Found"),
+            }
+        }
+        [year, "day", day, "answer"] => {
+            let mut form = String::new();
+            request.as_reader().read_to_string(&mut form).unwrap();
+            let field = |name: &str| {
+                form.split('&')
+                    .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+                    .unwrap_or_default()
+                    .to_owned()
+            };
+            let (level, answer) = (field("level"), field("answer"));
+
+            let Ok(right) =
+                fs::read_to_string(fixture(&format!("{year}/day{day}/answer{level}.txt")))
+            else {
+                return text(404, "404 Not Found");
+            };
+            let right = right.trim();
+
+            let key = (year.to_owned(), day.to_owned(), level);
+            let mut solved = solved.lock().unwrap();
+            if solved.contains(&key) {
+                return article(
+                    "You don't seem to be solving the right level.  Did you already complete it?",
+                );
+            }
+
+            if answer == right {
+                solved.insert(key);
+                return article("That's the right answer!  You are one gold star closer.");
+            }
+
+            let hint = match (answer.parse::<i64>(), right.parse::<i64>()) {
+                (Ok(answer), Ok(right)) if answer > right => "  your answer is too high.",
+                (Ok(answer), Ok(right)) if answer < right => "  your answer is too low.",
+                _ => "",
+            };
+            article(&format!(
+                "That's not the right answer;{hint}  Please wait one minute before trying again."
+            ))
+        }
+        _ => text(404, "404 Not Found"),
+    }
+}
+
+// answers come back as a whole page with the message in its only <article>
+fn article(message: &str) -> Response<Cursor<Vec<u8>>> {
+    let page = format!(
+        "<!DOCTYPE html>\n<html><head><title>Day - Advent of Code</title></head>\n\
+         <body><main>\n<article><p>{message}</p></article>\n</main></body></html>\n"
+    );
+    Response::from_string(page)
+        .with_header(Header::from_bytes("Content-Type", "text/html").unwrap())
+}
+
+fn text(status: u16, body: &str) -> Response<Cursor<Vec<u8>>> {
+    Response::from_string(body).with_status_code(status)
+}
@@ -0,0 +1,83 @@
+use aoc::{
+    differential::{self, DiffError, Failure, Summary},
+    solution::Comparison,
+};
+
+#[test]
+fn clever_solutions_agree_with_their_references() {
+    for day in [11, 15, 16, 21] {
+        let summary = differential::differential(2022, day, 0..5, 6).unwrap();
+        assert_eq!(
+            summary,
+            Summary {
+                compared: 5,
+                mismatch: None
+            },
+            "day {day}"
+        );
+    }
+}
+
+#[test]
+fn inputs_too_big_for_the_reference_are_not_counted() {
+    let summary = differential::differential(2022, 16, 0..3, 60).unwrap();
+
+    assert_eq!(summary.compared, 0);
+}
+
+#[test]
+fn days_without_a_reference_say_so() {
+    assert_eq!(
+        differential::differential(2022, 1, 0..5, 6),
+        Err(DiffError::NoReference { year: 2022, day: 1 })
+    );
+}
+
+#[test]
+fn minimising_keeps_the_failure_but_little_else() {
+    // anything with a number of at least ten "disagrees", anything with 99 panics instead
+    let fails = |input: &str| {
+        let numbers: Vec<u32> = input.split_whitespace().flat_map(str::parse).collect();
+        if numbers.contains(&99) {
+            Some(Failure::Panicked("99".to_owned()))
+        } else {
+            numbers.iter().any(|&n| n >= 10).then(|| {
+                Failure::Disagreed(Comparison {
+                    what: "part 1".to_owned(),
+                    reference: 0.into(),
+                    solution: numbers.len().into(),
+                })
+            })
+        }
+    };
+    let input = "3 4\n\n5\n198\n\n7\n".to_owned();
+
+    let (input, failure) = differential::minimise(input.clone(), fails(&input).unwrap(), fails);
+
+    // halving 198 gives 99, which fails differently
+    assert_eq!(input, "198\n");
+    assert_eq!(failure, fails("198\n").unwrap());
+}
+
+#[test]
+fn minimising_keeps_to_the_same_panic() {
+    // even numbers of at least ten index out of bounds, odd ones overflow
+    let fails = |input: &str| {
+        let n: u32 = input.trim().parse().ok()?;
+        (n >= 10).then(|| {
+            Failure::Panicked(match n % 2 {
+                0 => format!("index out of bounds: the len is 3 but the index is {n}"),
+                _ => "attempt to multiply with overflow".to_owned(),
+            })
+        })
+    };
+
+    // halving 21 gives 10, which panics some other way
+    let (input, _) = differential::minimise("21\n".to_owned(), fails("21\n").unwrap(), fails);
+    assert_eq!(input, "21\n");
+
+    // but the same panic about other numbers is still the same panic
+    let (input, failure) = differential::minimise("40\n".to_owned(), fails("40\n").unwrap(), fails);
+    assert_eq!(input, "10\n");
+    assert_eq!(failure, fails("10\n").unwrap());
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use std::{fs, fs::File};
+
+use aoc::visualise::{
+    export::{GifSink, Palette, PngSink},
+    Frame, FrameSink,
+};
+use common::scratch;
+
+#[test]
+fn palette_overrides_the_defaults() {
+    let palette = Palette::parse("#=ffffff,o=c0a000").unwrap();
+
+    assert_eq!(palette.colour('#'), [0xff, 0xff, 0xff]);
+    assert_eq!(palette.colour('o'), [0xc0, 0xa0, 0x00]);
+    assert_eq!(palette.colour('.'), Palette::default().colour('.'));
+
+    for bad in ["#", "#=fff", "#=gggggg", "##ffffff"] {
+        assert!(Palette::parse(bad).is_err(), "{bad}");
+    }
+}
+
+#[test]
+fn png_frames_are_numbered_and_scaled() {
+    let dir = scratch("export-png");
+    let palette = Palette::parse("#=ff0000,.=0000ff").unwrap();
+    let mut sink = PngSink::new(&dir, palette, 3);
+
+    sink.frame(&Frame::from_text("", "#.\n..\n..")).unwrap();
+    sink.frame(&Frame::from_text("", "..")).unwrap();
+    sink.finish().unwrap();
+
+    let mut reader = png::Decoder::new(File::open(dir.join("frame00001.png")).unwrap())
+        .read_info()
+        .unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    reader.next_frame(&mut pixels).unwrap();
+
+    assert_eq!((reader.info().width, reader.info().height), (6, 9));
+    // the top left cell is three pixels of red, then blue
+    assert_eq!(pixels[..12], [255, 0, 0, 255, 0, 0, 255, 0, 0, 0, 0, 255]);
+    assert!(dir.join("frame00002.png").exists());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn gif_holds_every_frame_at_the_largest_size() {
+    let path = scratch("export-run.gif");
+    let mut sink = GifSink::new(&path, Palette::default(), 2, 10);
+
+    sink.frame(&Frame::from_text("", "#")).unwrap();
+    sink.frame(&Frame::from_text("", "##\n##\n##")).unwrap();
+    sink.frame(&Frame::from_text("", "#..")).unwrap();
+    sink.finish().unwrap();
+
+    let mut decoder = gif::DecodeOptions::new()
+        .read_info(File::open(&path).unwrap())
+        .unwrap();
+    assert_eq!((decoder.width(), decoder.height()), (6, 6));
+
+    let mut sizes = Vec::new();
+    while let Some(frame) = decoder.read_next_frame().unwrap() {
+        assert_eq!(frame.delay, 10);
+        sizes.push((frame.width, frame.height));
+    }
+    // each padded out to the largest before it, as they're written as they come
+    assert_eq!(sizes, [(2, 2), (4, 6), (6, 6)]);
+    fs::remove_file(path).unwrap();
+}
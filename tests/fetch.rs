@@ -0,0 +1,98 @@
+mod common;
+
+use std::fs;
+
+use aoc::{
+    client::ClientError,
+    inputs::{CacheDir, Cached, Fetcher, InputError, InputSource},
+};
+use common::{scratch, MockAoc, RATE_LIMITED, SESSION};
+
+#[test]
+fn fetches_an_input() {
+    let server = MockAoc::start();
+
+    let input = server.client(Some(SESSION)).input(2022, 1).unwrap();
+
+    assert_eq!(
+        input,
+        fs::read_to_string(common::fixture("2022/day1/input.txt")).unwrap()
+    );
+}
+
+#[test]
+fn caches_what_it_fetches() {
+    let server = MockAoc::start();
+    let dir = scratch("fetch-cache");
+    let source = Cached {
+        cache: CacheDir::new(&dir, 2022),
+        fallback: Fetcher {
+            client: server.client(Some(SESSION)),
+            year: 2022,
+        },
+    };
+
+    let input = source.load(1).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(dir.join("2022/day01.txt")).unwrap(),
+        input
+    );
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn locked_day_is_not_unlocked() {
+    let server = MockAoc::start();
+
+    let err = server.client(Some(SESSION)).input(2022, 25).unwrap_err();
+
+    assert!(matches!(err, ClientError::NotUnlocked), "{err:?}");
+}
+
+#[test]
+fn missing_session_is_rejected() {
+    let server = MockAoc::start();
+
+    for session in [None, Some("expired")] {
+        let err = server.client(session).input(2022, 1).unwrap_err();
+        assert!(matches!(err, ClientError::BadSession), "{err:?}");
+    }
+}
+
+#[test]
+fn rate_limit_reports_when_to_retry() {
+    let server = MockAoc::start();
+
+    let err = server
+        .client(Some(RATE_LIMITED))
+        .input(2022, 1)
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            err,
+            ClientError::RateLimited {
+                retry_after: Some(30)
+            }
+        ),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn fetch_errors_reach_the_runner() {
+    let server = MockAoc::start();
+    let source = Fetcher {
+        client: server.client(Some(SESSION)),
+        year: 2022,
+    };
+
+    let err = source.load(25).unwrap_err();
+
+    assert!(matches!(err, InputError::Fetch(ClientError::NotUnlocked)));
+    assert_eq!(
+        err.to_string(),
+        "fetching input failed: that puzzle is not unlocked yet"
+    );
+}
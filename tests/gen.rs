@@ -0,0 +1,56 @@
+use aoc::{
+    aoc_days,
+    generate::{self, GenerateError},
+};
+
+#[test]
+fn every_day_can_solve_what_it_generates() {
+    for day in aoc_days::registered() {
+        // the smallest sizes as well, where a generator is most likely to give too little
+        for size in [0, 1, 2, 3, 5] {
+            for seed in 0..3 {
+                let input = generate::generate(day.year, day.day, seed, size).unwrap();
+                // only the parts that are solved, an unsolved part 2 may never finish
+                for &part in day.parts {
+                    if let Err(err) = (day.solver)(input.clone(), Some(part)) {
+                        panic!("size {size}, seed {seed}: {err}");
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn a_seed_always_gives_the_same_input() {
+    for day in aoc_days::registered() {
+        let input = |seed| generate::generate(day.year, day.day, seed, 20).unwrap();
+
+        assert_eq!(input(7), input(7), "day {}", day.day);
+        assert_ne!(input(7), input(8), "day {}", day.day);
+    }
+}
+
+#[test]
+fn size_scales_the_input() {
+    let lines = |size| {
+        generate::generate(2022, 2, 0, size)
+            .unwrap()
+            .lines()
+            .count()
+    };
+
+    assert_eq!(lines(10), 10);
+    assert_eq!(lines(1000), 1000);
+}
+
+#[test]
+fn days_without_a_solution_have_nothing_to_generate() {
+    assert_eq!(
+        generate::generate(2022, 25, 0, 10),
+        Err(GenerateError::NoSolution {
+            year: 2022,
+            day: 25
+        })
+    );
+}
@@ -0,0 +1,111 @@
+use std::path::Path;
+
+use aoc::leaderboard::{DayStats, Leaderboard, Scoring, Solve};
+
+fn board() -> Leaderboard {
+    Leaderboard::load(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/leaderboard.json"),
+    )
+    .unwrap()
+}
+
+fn ranking(board: &Leaderboard, scoring: Scoring) -> Vec<String> {
+    board
+        .standings(scoring)
+        .into_iter()
+        .map(|s| s.name)
+        .collect()
+}
+
+#[test]
+fn puzzles_unlock_at_midnight_eastern() {
+    let board = board();
+
+    // 2022-12-01T05:00:00Z
+    assert_eq!(board.unlock(1), 1669870800);
+    assert_eq!(board.unlock(25), 1669870800 + 24 * 86400);
+}
+
+#[test]
+fn timelines_are_since_each_unlock() {
+    let board = board();
+
+    let solves = board.solves(&board.members["2"]);
+
+    assert_eq!(
+        solves,
+        [
+            Solve {
+                day: 1,
+                part1: 300,
+                part2: Some(1500)
+            },
+            Solve {
+                day: 2,
+                part1: 1800,
+                part2: None
+            },
+        ]
+    );
+    assert_eq!(solves[0].delta(), Some(1200));
+}
+
+#[test]
+fn local_scores_match_the_site() {
+    let board = board();
+
+    for standing in board.standings(Scoring::Local) {
+        let member = board
+            .members
+            .values()
+            .find(|m| m.display_name() == standing.name)
+            .unwrap();
+        assert_eq!(standing.local, member.local_score, "{}", standing.name);
+    }
+}
+
+#[test]
+fn scoring_rules_rank_differently() {
+    let board = board();
+
+    assert_eq!(
+        ranking(&board, Scoring::Local),
+        ["Ada", "Brian", "(anonymous user #3)", "Lurker"]
+    );
+    assert_eq!(
+        ranking(&board, Scoring::Stars),
+        ["Ada", "Brian", "(anonymous user #3)", "Lurker"]
+    );
+    // the anonymous member did part 2 a minute after part 1
+    assert_eq!(
+        ranking(&board, Scoring::Delta),
+        ["Ada", "(anonymous user #3)", "Brian", "Lurker"]
+    );
+}
+
+#[test]
+fn days_have_difficulty_stats() {
+    let board = board();
+
+    assert_eq!(
+        board.days(),
+        [
+            DayStats {
+                day: 1,
+                part1: 3,
+                part2: 3,
+                median_part1: Some(600),
+                median_part2: Some(1500),
+                median_delta: Some(300),
+            },
+            DayStats {
+                day: 2,
+                part1: 2,
+                part2: 1,
+                median_part1: Some(1800),
+                median_part2: Some(3000),
+                median_delta: Some(1800),
+            },
+        ]
+    );
+}
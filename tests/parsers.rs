@@ -0,0 +1,134 @@
+use std::{
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use aoc::{
+    aoc_days::{self, Registration},
+    generate,
+    solution::Solution,
+    y2022::day13::{self, Day13, PacketStream},
+};
+use proptest::prelude::*;
+
+// fails if `input` makes the day's parser panic or take too long, an error is fine
+fn parses_or_errs(day: &Registration, input: &str) -> Result<(), TestCaseError> {
+    let (parsed, done) = mpsc::channel();
+    let (parse, input) = (day.parse, input.to_owned());
+    // off on its own, so a parser that never returns can be left behind
+    thread::spawn(move || {
+        let _ = parse(input);
+        let _ = parsed.send(());
+    });
+
+    match done.recv_timeout(Duration::from_secs(5)) {
+        Ok(()) => Ok(()),
+        Err(RecvTimeoutError::Disconnected) => Err(TestCaseError::fail(format!(
+            "day {}'s parser panicked",
+            day.day
+        ))),
+        Err(RecvTimeoutError::Timeout) => Err(TestCaseError::fail(format!(
+            "day {}'s parser is still going",
+            day.day
+        ))),
+    }
+}
+
+// a small edit to an input, at a position given as a fraction of its length
+#[derive(Debug, Clone)]
+enum Edit {
+    Delete(f64, usize),
+    Insert(f64, char),
+    Repeat(f64, usize),
+}
+
+// what puzzle inputs are mostly made of
+const PUNCTUATION: &str = " \n,:-=[]0123456789xyz";
+
+fn edit() -> impl Strategy<Value = Edit> {
+    let at = 0.0..1.0;
+    let c = prop::sample::select(PUNCTUATION.chars().collect::<Vec<_>>());
+    prop_oneof![
+        (at.clone(), 1..8usize).prop_map(|(at, len)| Edit::Delete(at, len)),
+        (at.clone(), c).prop_map(|(at, c)| Edit::Insert(at, c)),
+        (at, 1..16usize).prop_map(|(at, len)| Edit::Repeat(at, len)),
+    ]
+}
+
+fn apply(input: &str, edits: &[Edit]) -> String {
+    let mut input: Vec<char> = input.chars().collect();
+    for edit in edits {
+        let at = |fraction: f64| (fraction * input.len() as f64) as usize;
+        match *edit {
+            Edit::Delete(fraction, len) => {
+                let at = at(fraction);
+                input.drain(at..(at + len).min(input.len()));
+            }
+            Edit::Insert(fraction, c) => input.insert(at(fraction), c),
+            Edit::Repeat(fraction, len) => {
+                let at = at(fraction);
+                let repeated = input[at..(at + len).min(input.len())].to_vec();
+                input.splice(at..at, repeated);
+            }
+        }
+    }
+    input.into_iter().collect()
+}
+
+fn packet() -> impl Strategy<Value = PacketStream> {
+    any::<u32>()
+        .prop_map(PacketStream::Value)
+        .prop_recursive(6, 64, 8, |inner| {
+            prop::collection::vec(inner, 0..8).prop_map(PacketStream::List)
+        })
+}
+
+fn packet_list() -> impl Strategy<Value = PacketStream> {
+    prop::collection::vec(packet(), 0..8).prop_map(PacketStream::List)
+}
+
+proptest! {
+    #[test]
+    fn any_text_parses_or_errs(input in "\\PC*") {
+        for day in aoc_days::registered() {
+            parses_or_errs(day, &input)?;
+        }
+    }
+
+    #[test]
+    fn edited_inputs_parse_or_err(
+        seed in any::<u64>(),
+        edits in prop::collection::vec(edit(), 1..6),
+    ) {
+        for day in aoc_days::registered() {
+            let input = generate::generate(day.year, day.day, seed, 5).unwrap();
+            parses_or_errs(day, &apply(&input, &edits))?;
+        }
+    }
+
+    #[test]
+    fn packets_round_trip(left in packet_list(), right in packet_list()) {
+        let written = left.to_string();
+        prop_assert_eq!(day13::packet_stream(&written), Ok(("", left.clone())));
+
+        let pair = format!("{left}\n{right}\n");
+        prop_assert_eq!(Day13::parse(pair).unwrap(), vec![(left, right)]);
+    }
+}
+
+#[test]
+fn deeply_nested_packets_are_an_error() {
+    let deep = format!("{}\n[]\n", "[".repeat(100_000));
+
+    assert!(Day13::parse(deep).is_err());
+}
+
+#[test]
+fn parse_errors_say_which_day_they_came_from() {
+    for day in aoc_days::registered() {
+        if let Err(err) = (day.parse)("?\n".to_owned()) {
+            assert_eq!((err.year, err.day), (day.year, day.day));
+        }
+    }
+}
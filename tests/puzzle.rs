@@ -0,0 +1,117 @@
+mod common;
+
+use std::fs;
+
+use aoc::puzzle::{fetch_puzzle, Puzzle, PuzzleError};
+use common::{scratch, MockAoc, SESSION};
+
+fn page() -> Puzzle {
+    Puzzle::parse(&fs::read_to_string(common::fixture("2022/day1/puzzle.html")).unwrap()).unwrap()
+}
+
+#[test]
+fn finds_the_example_and_its_answers() {
+    let puzzle = page();
+
+    assert_eq!(puzzle.parts.len(), 2);
+    assert_eq!(
+        puzzle.examples(),
+        [fs::read_to_string(common::fixture("2022/day1/input.txt")).unwrap()]
+    );
+    assert_eq!(
+        puzzle.answers(),
+        [Some("24000".to_owned()), Some("45000".to_owned())]
+    );
+}
+
+#[test]
+fn converts_to_markdown() {
+    let markdown = page().markdown();
+
+    assert!(markdown.starts_with("## Day 1: Calorie Counting\n\nThe Elves take turns"));
+    assert!(markdown.contains("number of *Calories* in"));
+    assert!(markdown.contains("```\n1000\n2000\n"));
+    assert!(markdown.contains("get hungry & need a snack"));
+    assert!(markdown.contains("`24000` <- the fourth Elf with **`24000`** Calories."));
+    assert!(markdown.contains("- The third Elf with `24000` Calories\n"));
+    assert!(markdown.contains("`11000` Calories\n\nThe sum"));
+    assert!(markdown.contains("[your input](https://adventofcode.com/2022/day/1/input)"));
+    assert!(!markdown.contains("Your puzzle answer"));
+}
+
+#[test]
+fn fetches_and_fills_in_the_fixture() {
+    let server = MockAoc::start();
+    let dir = scratch("puzzle-fetch");
+    let examples = dir.join("examples");
+
+    let written = fetch_puzzle(
+        Some(&server.client(Some(SESSION))),
+        &dir.join("puzzles"),
+        &examples,
+        2022,
+        1,
+    )
+    .unwrap();
+
+    assert_eq!(written.len(), 4, "{written:?}");
+    assert!(dir.join("puzzles/2022/day01.html").exists());
+    assert!(dir.join("puzzles/2022/day01.md").exists());
+    assert_eq!(
+        fs::read_to_string(examples.join("2022/day01/answers.txt")).unwrap(),
+        "# input part1 part2\nexample.txt 24000 45000\n"
+    );
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn works_offline_from_the_cache() {
+    let dir = scratch("puzzle-offline");
+    let puzzles = dir.join("puzzles");
+
+    let err = fetch_puzzle(None, &puzzles, &dir.join("examples"), 2022, 1).unwrap_err();
+    assert!(matches!(err, PuzzleError::Missing(_)), "{err:?}");
+
+    fs::create_dir_all(puzzles.join("2022")).unwrap();
+    fs::copy(
+        common::fixture("2022/day1/puzzle.html"),
+        puzzles.join("2022/day01.html"),
+    )
+    .unwrap();
+    fetch_puzzle(None, &puzzles, &dir.join("examples"), 2022, 1).unwrap();
+
+    assert!(dir.join("examples/2022/day01/example.txt").exists());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn keeps_answers_written_by_hand() {
+    let dir = scratch("puzzle-by-hand");
+    let puzzles = dir.join("puzzles");
+    let fixture = dir.join("examples/2022/day01");
+    fs::create_dir_all(puzzles.join("2022")).unwrap();
+    fs::create_dir_all(&fixture).unwrap();
+    fs::copy(
+        common::fixture("2022/day1/puzzle.html"),
+        puzzles.join("2022/day01.html"),
+    )
+    .unwrap();
+    fs::write(fixture.join("example.txt"), "1\n").unwrap();
+    fs::write(
+        fixture.join("answers.txt"),
+        "# input part1 part2\nexample.txt 1 ?\nlarger.txt 5 6\n",
+    )
+    .unwrap();
+
+    fetch_puzzle(None, &puzzles, &dir.join("examples"), 2022, 1).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(fixture.join("example.txt")).unwrap(),
+        "1\n"
+    );
+    assert_eq!(
+        fs::read_to_string(fixture.join("answers.txt")).unwrap(),
+        "# input part1 part2\nexample.txt 1 45000\nlarger.txt 5 6\n"
+    );
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,167 @@
+mod common;
+
+use std::{fs, path::PathBuf};
+
+use aoc::{
+    answers::AnswerStore,
+    client::{Hint, Verdict},
+    solution::{Answer, Part},
+    submit::{submit, SubmitError},
+};
+use common::{scratch, MockAoc, COOLING_DOWN, SESSION};
+
+fn empty_store(name: &str) -> (PathBuf, AnswerStore) {
+    let path = scratch(&format!("submit-{name}.txt"));
+    let store = AnswerStore::load(&path).unwrap();
+    (path, store)
+}
+
+#[test]
+fn right_answer_is_recorded() {
+    let server = MockAoc::start();
+    let (path, mut store) = empty_store("right");
+
+    let verdict = submit(
+        &server.client(Some(SESSION)),
+        &mut store,
+        2022,
+        1,
+        Part::One,
+        &Answer::Number(24000),
+    )
+    .unwrap();
+
+    assert_eq!(verdict, Verdict::Right);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1 1 24000\n");
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn wrong_answers_are_remembered_and_not_resubmitted() {
+    let server = MockAoc::start();
+    let client = server.client(Some(SESSION));
+    let (path, mut store) = empty_store("wrong");
+
+    let high = submit(
+        &client,
+        &mut store,
+        2022,
+        1,
+        Part::Two,
+        &Answer::Number(50000),
+    );
+    let low = submit(
+        &client,
+        &mut store,
+        2022,
+        1,
+        Part::Two,
+        &Answer::Number(40000),
+    );
+    assert_eq!(high.unwrap(), Verdict::Wrong(Some(Hint::TooHigh)));
+    assert_eq!(low.unwrap(), Verdict::Wrong(Some(Hint::TooLow)));
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "too-high 1 2 50000\ntoo-low 1 2 40000\n"
+    );
+
+    // the same answer, and anything beyond the bounds, never reaches the site
+    let mut store = AnswerStore::load(&path).unwrap();
+    for answer in [50000, 60000, 40000, 12] {
+        let err = submit(
+            &client,
+            &mut store,
+            2022,
+            1,
+            Part::Two,
+            &Answer::Number(answer),
+        );
+        assert!(matches!(err, Err(SubmitError::KnownWrong(_))), "{err:?}");
+    }
+
+    let right = submit(
+        &client,
+        &mut store,
+        2022,
+        1,
+        Part::Two,
+        &Answer::Number(45000),
+    );
+    assert_eq!(right.unwrap(), Verdict::Right);
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn cooling_down_reports_how_long_to_wait() {
+    let server = MockAoc::start();
+    let (path, mut store) = empty_store("wait");
+
+    let verdict = submit(
+        &server.client(Some(COOLING_DOWN)),
+        &mut store,
+        2022,
+        1,
+        Part::One,
+        &Answer::Number(1),
+    )
+    .unwrap();
+
+    assert_eq!(verdict, Verdict::Wait { secs: 64 });
+    // waiting says nothing about the answer
+    assert_eq!(store.known_wrong(1, 1, "1"), None);
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn solved_part_is_already_solved() {
+    let server = MockAoc::start();
+    let client = server.client(Some(SESSION));
+
+    let (path, mut store) = empty_store("solved");
+    submit(
+        &client,
+        &mut store,
+        2022,
+        1,
+        Part::One,
+        &Answer::Number(24000),
+    )
+    .unwrap();
+    fs::remove_file(path).unwrap();
+
+    // a store that never heard of it still gets told by the site
+    let (path, mut store) = empty_store("solved-elsewhere");
+    let verdict = submit(
+        &client,
+        &mut store,
+        2022,
+        1,
+        Part::One,
+        &Answer::Number(24000),
+    );
+    assert_eq!(verdict.unwrap(), Verdict::AlreadySolved);
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn pictures_and_unsolved_parts_are_not_submitted() {
+    let server = MockAoc::start();
+    let client = server.client(Some(SESSION));
+    let (_, mut store) = empty_store("unsubmittable");
+
+    let picture = submit(
+        &client,
+        &mut store,
+        2022,
+        1,
+        Part::One,
+        &Answer::Picture("#.\n.#".into()),
+    );
+    let unsolved = submit(&client, &mut store, 2022, 1, Part::One, &Answer::Unsolved);
+
+    assert!(matches!(picture, Err(SubmitError::Picture)), "{picture:?}");
+    assert!(
+        matches!(unsolved, Err(SubmitError::Unsolved)),
+        "{unsolved:?}"
+    );
+}
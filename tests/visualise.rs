@@ -0,0 +1,77 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use aoc::{
+    inputs::Examples,
+    runner::{self, Status},
+    solution::Part,
+    visualise::{self, Frame, FrameSink, TextSink},
+};
+
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<Frame>>>);
+
+impl FrameSink for Recorder {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        self.0.lock().unwrap().push(frame.clone());
+        Ok(())
+    }
+}
+
+#[test]
+fn frames_pad_ragged_text() {
+    let frame = Frame::from_text("caption", "#..\n#\n");
+
+    assert_eq!((frame.width(), frame.height()), (3, 2));
+    assert_eq!(frame.get(2, 1), Some(' '));
+    assert_eq!(frame.get(3, 0), None);
+    assert_eq!(frame.to_string(), "#..\n#\n");
+}
+
+#[test]
+fn text_sink_writes_captioned_frames() {
+    let mut sink = TextSink(Vec::new());
+
+    sink.frame(&Frame::from_text("round 1", "#.\n.#")).unwrap();
+
+    assert_eq!(String::from_utf8(sink.0).unwrap(), "round 1\n#.\n.#\n\n");
+}
+
+// the only test installing a sink, as there is one for the whole process
+#[test]
+fn simulations_emit_frames_into_the_installed_sink() {
+    let source = Examples {
+        root: concat!(env!("CARGO_MANIFEST_DIR"), "/examples").into(),
+        year: 2022,
+    };
+    let recorder = Recorder::default();
+
+    assert!(!visualise::enabled());
+    visualise::install(Box::new(recorder.clone()));
+    let report = runner::run_day(&source, 2022, 23, 1, Some(Part::One));
+    visualise::finish().unwrap();
+
+    assert!(matches!(report.status, Status::Ok));
+    let frames = recorder.0.lock().unwrap();
+    // the 10 rounds before the answer, and the map it is read from
+    assert_eq!(frames.len(), 11);
+    assert_eq!(frames[0].caption, "round 0");
+    assert_eq!(frames[10].caption, "round 10");
+
+    // skipping frames starts counting again with the new sink
+    let every_fourth = Recorder::default();
+    visualise::install_every(Box::new(every_fourth.clone()), 4);
+    runner::run_day(&source, 2022, 23, 1, Some(Part::One));
+    visualise::finish().unwrap();
+
+    let captions: Vec<String> = every_fourth
+        .0
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|f| f.caption.clone())
+        .collect();
+    assert_eq!(captions, ["round 0", "round 4", "round 8"]);
+}